@@ -4,7 +4,6 @@ import android.content.Context
 import io.reactivex.Completable
 import io.reactivex.Single
 import jnr.ffi.LibraryLoader
-import jnr.ffi.Pointer
 import jnr.ffi.annotations.Delegate
 import java.io.File
 import java.io.InputStream
@@ -13,15 +12,28 @@ import java.util.concurrent.atomic.AtomicBoolean
 
 class JnrTurboSolverException(msg: String): RuntimeException(msg)
 
+/** Status codes returned by [NativeTurboSolverApi] functions. */
+private const val SOLVER_OK = 0
+
 interface NativeTurboSolverApi {
-    fun solver_create(grid: CharSequence, cb: SolverCreatedCallback)
-    fun solver_solve(solver_ptr: Pointer, cb: SolverResultCallback)
-    fun solver_destroy(solver_ptr: Pointer)
+    fun solver_create(grid: CharSequence, cb: SolverCreatedCallback): Int
+    fun solver_solve(solver: Long, cb: SolverResultCallback): Int
+    fun solver_destroy(solver: Long): Int
+    /** Description of the last failure on the calling thread. */
+    fun solver_last_error_message(): String?
+}
+
+/** Exception describing the failure, if [status] isn't [SOLVER_OK]. */
+private fun NativeTurboSolverApi.failure(status: Int): JnrTurboSolverException? {
+    if (status == SOLVER_OK) {
+        return null
+    }
+    return JnrTurboSolverException(solver_last_error_message() ?: "failed with status $status")
 }
 
 interface SolverCreatedCallback {
     @Delegate
-    fun onSolverCreated(solver_ptr: Pointer?, error: CharSequence?)
+    fun onSolverCreated(solver: Long, error: CharSequence?)
 }
 
 interface SolverResultCallback {
@@ -31,7 +43,7 @@ interface SolverResultCallback {
 
 class JnrTurboSolver(
         private val nativeSolver: NativeTurboSolverApi,
-        private val solverPtr: Pointer): TurboSolver {
+        private val solver: Long): TurboSolver {
 
     private val destroyed = AtomicBoolean(false)
 
@@ -40,7 +52,7 @@ class JnrTurboSolver(
             if (destroyed.get()) {
                 throw JnrTurboSolverException("solver is destroyed")
             }
-            nativeSolver.solver_solve(solverPtr, object: SolverResultCallback {
+            val status = nativeSolver.solver_solve(solver, object: SolverResultCallback {
                 override fun onSolverResult(solution: CharSequence?) {
                     if (emitter.isDisposed) {
                         return
@@ -55,6 +67,7 @@ class JnrTurboSolver(
                     }
                 }
             })
+            nativeSolver.failure(status)?.let { emitter.tryOnError(it) }
         }
     }
 
@@ -63,8 +76,12 @@ class JnrTurboSolver(
             if (!destroyed.compareAndSet(false, true)) {
                 throw JnrTurboSolverException("solver is already destroyed")
             }
-            nativeSolver.solver_destroy(solverPtr)
-            emitter.onComplete()
+            val failure = nativeSolver.failure(nativeSolver.solver_destroy(solver))
+            if (failure != null) {
+                emitter.onError(failure)
+            } else {
+                emitter.onComplete()
+            }
         }
     }
 }
@@ -135,8 +152,8 @@ class JnrTurboSolverFactory private constructor(
 
     override fun create(grid: String): Single<TurboSolver> {
         return Single.create<TurboSolver> { emitter ->
-            nativeSolver.solver_create(grid, object: SolverCreatedCallback {
-                override fun onSolverCreated(solver_ptr: Pointer?, error: CharSequence?) {
+            val status = nativeSolver.solver_create(grid, object: SolverCreatedCallback {
+                override fun onSolverCreated(solver: Long, error: CharSequence?) {
                     if (emitter.isDisposed) {
                         return
                     }
@@ -152,13 +169,15 @@ class JnrTurboSolverFactory private constructor(
                         return
                     }
 
-                    if (solver_ptr != null) {
-                        emitter.onSuccess(JnrTurboSolver(nativeSolver, solver_ptr))
+                    if (solver != 0L) {
+                        emitter.onSuccess(JnrTurboSolver(nativeSolver, solver))
                     } else {
-                        kotlin.error("error == null and solver_ptr == null?")
+                        kotlin.error("error == null and solver == 0?")
                     }
                 }
             })
+            // The callback has already reported parse errors, this covers the rest.
+            nativeSolver.failure(status)?.let { emitter.tryOnError(it) }
         }
     }
 }
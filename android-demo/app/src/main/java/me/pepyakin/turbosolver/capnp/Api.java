@@ -5,7 +5,7 @@ package me.pepyakin.turbosolver.capnp;
 
 public final class Api {
   public static class Req {
-    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)1);
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)2,(short)1);
     public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
       public Factory() {
       }
@@ -34,6 +34,14 @@ public final class Api {
           case 0 : return Which.CREATE_SOLVER_REQ;
           case 1 : return Which.SOLVE_REQ;
           case 2 : return Which.DESTROY_REQ;
+          case 3 : return Which.LIST_SOLVERS_REQ;
+          case 4 : return Which.DESCRIBE_SOLVER_REQ;
+          case 5 : return Which.CLONE_SOLVER_REQ;
+          case 6 : return Which.SOLVE_ASSUMING_REQ;
+          case 7 : return Which.USAGE_REQ;
+          case 8 : return Which.SUBSCRIBE_EVENTS_REQ;
+          case 9 : return Which.BATCH_REQ;
+          case 10 : return Which.HANDSHAKE_REQ;
           default: return Which._NOT_IN_SCHEMA;
         }
       }
@@ -95,6 +103,141 @@ public final class Api {
         _setShortField(2, (short)Req.Which.DESTROY_REQ.ordinal());
         return _initPointerField(me.pepyakin.turbosolver.capnp.Api.DestroyReq.factory,0, 0);
       }
+      public final boolean isListSolversReq() {
+        return which() == Req.Which.LIST_SOLVERS_REQ;
+      }
+      public final org.capnproto.Void getListSolversReq() {
+        assert which() == Req.Which.LIST_SOLVERS_REQ:
+                    "Must check which() before get()ing a union member.";
+        return org.capnproto.Void.VOID;
+      }
+      public final void setListSolversReq(org.capnproto.Void value) {
+        _setShortField(2, (short)Req.Which.LIST_SOLVERS_REQ.ordinal());
+      }
+
+      public final boolean isDescribeSolverReq() {
+        return which() == Req.Which.DESCRIBE_SOLVER_REQ;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.DescribeSolverReq.Builder getDescribeSolverReq() {
+        assert which() == Req.Which.DESCRIBE_SOLVER_REQ:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.DescribeSolverReq.factory, 0, null, 0);
+      }
+      public final void setDescribeSolverReq(me.pepyakin.turbosolver.capnp.Api.DescribeSolverReq.Reader value) {
+        _setShortField(2, (short)Req.Which.DESCRIBE_SOLVER_REQ.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.DescribeSolverReq.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.DescribeSolverReq.Builder initDescribeSolverReq() {
+        _setShortField(2, (short)Req.Which.DESCRIBE_SOLVER_REQ.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.DescribeSolverReq.factory,0, 0);
+      }
+      public final boolean isCloneSolverReq() {
+        return which() == Req.Which.CLONE_SOLVER_REQ;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.CloneSolverReq.Builder getCloneSolverReq() {
+        assert which() == Req.Which.CLONE_SOLVER_REQ:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.CloneSolverReq.factory, 0, null, 0);
+      }
+      public final void setCloneSolverReq(me.pepyakin.turbosolver.capnp.Api.CloneSolverReq.Reader value) {
+        _setShortField(2, (short)Req.Which.CLONE_SOLVER_REQ.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.CloneSolverReq.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.CloneSolverReq.Builder initCloneSolverReq() {
+        _setShortField(2, (short)Req.Which.CLONE_SOLVER_REQ.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.CloneSolverReq.factory,0, 0);
+      }
+      public final boolean isSolveAssumingReq() {
+        return which() == Req.Which.SOLVE_ASSUMING_REQ;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.SolveAssumingReq.Builder getSolveAssumingReq() {
+        assert which() == Req.Which.SOLVE_ASSUMING_REQ:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.SolveAssumingReq.factory, 0, null, 0);
+      }
+      public final void setSolveAssumingReq(me.pepyakin.turbosolver.capnp.Api.SolveAssumingReq.Reader value) {
+        _setShortField(2, (short)Req.Which.SOLVE_ASSUMING_REQ.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.SolveAssumingReq.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.SolveAssumingReq.Builder initSolveAssumingReq() {
+        _setShortField(2, (short)Req.Which.SOLVE_ASSUMING_REQ.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.SolveAssumingReq.factory,0, 0);
+      }
+      public final boolean isUsageReq() {
+        return which() == Req.Which.USAGE_REQ;
+      }
+      public final org.capnproto.Void getUsageReq() {
+        assert which() == Req.Which.USAGE_REQ:
+                    "Must check which() before get()ing a union member.";
+        return org.capnproto.Void.VOID;
+      }
+      public final void setUsageReq(org.capnproto.Void value) {
+        _setShortField(2, (short)Req.Which.USAGE_REQ.ordinal());
+      }
+
+      public final boolean isSubscribeEventsReq() {
+        return which() == Req.Which.SUBSCRIBE_EVENTS_REQ;
+      }
+      public final org.capnproto.Void getSubscribeEventsReq() {
+        assert which() == Req.Which.SUBSCRIBE_EVENTS_REQ:
+                    "Must check which() before get()ing a union member.";
+        return org.capnproto.Void.VOID;
+      }
+      public final void setSubscribeEventsReq(org.capnproto.Void value) {
+        _setShortField(2, (short)Req.Which.SUBSCRIBE_EVENTS_REQ.ordinal());
+      }
+
+      public final me.pepyakin.turbosolver.capnp.Api.Priority getPriority() {
+        switch(_getShortField(3)) {
+          case 0 : return me.pepyakin.turbosolver.capnp.Api.Priority.NORMAL;
+          case 1 : return me.pepyakin.turbosolver.capnp.Api.Priority.LOW;
+          case 2 : return me.pepyakin.turbosolver.capnp.Api.Priority.HIGH;
+          default: return me.pepyakin.turbosolver.capnp.Api.Priority._NOT_IN_SCHEMA;
+        }
+      }
+      public final void setPriority(me.pepyakin.turbosolver.capnp.Api.Priority value) {
+        _setShortField(3, (short)value.ordinal());
+      }
+
+      public final boolean getReportProgress() {
+        return _getBooleanField(64);
+      }
+      public final void setReportProgress(boolean value) {
+        _setBooleanField(64, value);
+      }
+
+      public final boolean isBatchReq() {
+        return which() == Req.Which.BATCH_REQ;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.BatchReq.Builder getBatchReq() {
+        assert which() == Req.Which.BATCH_REQ:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.BatchReq.factory, 0, null, 0);
+      }
+      public final void setBatchReq(me.pepyakin.turbosolver.capnp.Api.BatchReq.Reader value) {
+        _setShortField(2, (short)Req.Which.BATCH_REQ.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.BatchReq.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.BatchReq.Builder initBatchReq() {
+        _setShortField(2, (short)Req.Which.BATCH_REQ.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.BatchReq.factory,0, 0);
+      }
+      public final boolean isHandshakeReq() {
+        return which() == Req.Which.HANDSHAKE_REQ;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.HandshakeReq.Builder getHandshakeReq() {
+        assert which() == Req.Which.HANDSHAKE_REQ:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.HandshakeReq.factory, 0, null, 0);
+      }
+      public final void setHandshakeReq(me.pepyakin.turbosolver.capnp.Api.HandshakeReq.Reader value) {
+        _setShortField(2, (short)Req.Which.HANDSHAKE_REQ.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.HandshakeReq.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.HandshakeReq.Builder initHandshakeReq() {
+        _setShortField(2, (short)Req.Which.HANDSHAKE_REQ.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.HandshakeReq.factory,0, 0);
+      }
     }
 
     public static final class Reader extends org.capnproto.StructReader {
@@ -107,6 +250,14 @@ public final class Api {
           case 0 : return Which.CREATE_SOLVER_REQ;
           case 1 : return Which.SOLVE_REQ;
           case 2 : return Which.DESTROY_REQ;
+          case 3 : return Which.LIST_SOLVERS_REQ;
+          case 4 : return Which.DESCRIBE_SOLVER_REQ;
+          case 5 : return Which.CLONE_SOLVER_REQ;
+          case 6 : return Which.SOLVE_ASSUMING_REQ;
+          case 7 : return Which.USAGE_REQ;
+          case 8 : return Which.SUBSCRIBE_EVENTS_REQ;
+          case 9 : return Which.BATCH_REQ;
+          case 10 : return Which.HANDSHAKE_REQ;
           default: return Which._NOT_IN_SCHEMA;
         }
       }
@@ -150,19 +301,134 @@ public final class Api {
         return _getPointerField(me.pepyakin.turbosolver.capnp.Api.DestroyReq.factory,0,null, 0);
       }
 
+      public final boolean isListSolversReq() {
+        return which() == Req.Which.LIST_SOLVERS_REQ;
+      }
+      public final org.capnproto.Void getListSolversReq() {
+        assert which() == Req.Which.LIST_SOLVERS_REQ:
+                    "Must check which() before get()ing a union member.";
+        return org.capnproto.Void.VOID;
+      }
+
+      public final boolean isDescribeSolverReq() {
+        return which() == Req.Which.DESCRIBE_SOLVER_REQ;
+      }
+      public boolean hasDescribeSolverReq() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.DescribeSolverReq.Reader getDescribeSolverReq() {
+        assert which() == Req.Which.DESCRIBE_SOLVER_REQ:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.DescribeSolverReq.factory,0,null, 0);
+      }
+
+      public final boolean isCloneSolverReq() {
+        return which() == Req.Which.CLONE_SOLVER_REQ;
+      }
+      public boolean hasCloneSolverReq() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.CloneSolverReq.Reader getCloneSolverReq() {
+        assert which() == Req.Which.CLONE_SOLVER_REQ:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.CloneSolverReq.factory,0,null, 0);
+      }
+
+      public final boolean isSolveAssumingReq() {
+        return which() == Req.Which.SOLVE_ASSUMING_REQ;
+      }
+      public boolean hasSolveAssumingReq() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.SolveAssumingReq.Reader getSolveAssumingReq() {
+        assert which() == Req.Which.SOLVE_ASSUMING_REQ:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.SolveAssumingReq.factory,0,null, 0);
+      }
+
+      public final boolean isUsageReq() {
+        return which() == Req.Which.USAGE_REQ;
+      }
+      public final org.capnproto.Void getUsageReq() {
+        assert which() == Req.Which.USAGE_REQ:
+                    "Must check which() before get()ing a union member.";
+        return org.capnproto.Void.VOID;
+      }
+
+      public final boolean isSubscribeEventsReq() {
+        return which() == Req.Which.SUBSCRIBE_EVENTS_REQ;
+      }
+      public final org.capnproto.Void getSubscribeEventsReq() {
+        assert which() == Req.Which.SUBSCRIBE_EVENTS_REQ:
+                    "Must check which() before get()ing a union member.";
+        return org.capnproto.Void.VOID;
+      }
+
+      public final me.pepyakin.turbosolver.capnp.Api.Priority getPriority() {
+        switch(_getShortField(3)) {
+          case 0 : return me.pepyakin.turbosolver.capnp.Api.Priority.NORMAL;
+          case 1 : return me.pepyakin.turbosolver.capnp.Api.Priority.LOW;
+          case 2 : return me.pepyakin.turbosolver.capnp.Api.Priority.HIGH;
+          default: return me.pepyakin.turbosolver.capnp.Api.Priority._NOT_IN_SCHEMA;
+        }
+      }
+
+      public final boolean getReportProgress() {
+        return _getBooleanField(64);
+      }
+
+      public final boolean isBatchReq() {
+        return which() == Req.Which.BATCH_REQ;
+      }
+      public boolean hasBatchReq() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.BatchReq.Reader getBatchReq() {
+        assert which() == Req.Which.BATCH_REQ:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.BatchReq.factory,0,null, 0);
+      }
+
+      public final boolean isHandshakeReq() {
+        return which() == Req.Which.HANDSHAKE_REQ;
+      }
+      public boolean hasHandshakeReq() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.HandshakeReq.Reader getHandshakeReq() {
+        assert which() == Req.Which.HANDSHAKE_REQ:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.HandshakeReq.factory,0,null, 0);
+      }
+
     }
 
     public enum Which {
       CREATE_SOLVER_REQ,
       SOLVE_REQ,
       DESTROY_REQ,
+      LIST_SOLVERS_REQ,
+      DESCRIBE_SOLVER_REQ,
+      CLONE_SOLVER_REQ,
+      SOLVE_ASSUMING_REQ,
+      USAGE_REQ,
+      SUBSCRIBE_EVENTS_REQ,
+      BATCH_REQ,
+      HANDSHAKE_REQ,
       _NOT_IN_SCHEMA,
     }
   }
 
 
+  public enum Priority {
+    NORMAL,
+    LOW,
+    HIGH,
+    _NOT_IN_SCHEMA,
+  }
+
   public static class Resp {
-    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)2,(short)1);
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)2,(short)2);
     public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
       public Factory() {
       }
@@ -190,6 +456,9 @@ public final class Api {
         switch(_getShortField(4)) {
           case 0 : return Which.ERR;
           case 1 : return Which.OK;
+          case 2 : return Which.EVENT;
+          case 3 : return Which.WORKER_RESTARTED;
+          case 4 : return Which.PROGRESS;
           default: return Which._NOT_IN_SCHEMA;
         }
       }
@@ -232,6 +501,71 @@ public final class Api {
         _setShortField(4, (short)Resp.Which.OK.ordinal());
         return _initPointerField(me.pepyakin.turbosolver.capnp.Api.OkResp.factory,0, 0);
       }
+      public final boolean isEvent() {
+        return which() == Resp.Which.EVENT;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.Event.Builder getEvent() {
+        assert which() == Resp.Which.EVENT:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Event.factory, 0, null, 0);
+      }
+      public final void setEvent(me.pepyakin.turbosolver.capnp.Api.Event.Reader value) {
+        _setShortField(4, (short)Resp.Which.EVENT.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.Event.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.Event.Builder initEvent() {
+        _setShortField(4, (short)Resp.Which.EVENT.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.Event.factory,0, 0);
+      }
+      public final boolean isWorkerRestarted() {
+        return which() == Resp.Which.WORKER_RESTARTED;
+      }
+      public final boolean hasWorkerRestarted() {
+        if (which() != Resp.Which.WORKER_RESTARTED) return false;
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.Text.Builder getWorkerRestarted() {
+        assert which() == Resp.Which.WORKER_RESTARTED:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
+      }
+      public final void setWorkerRestarted(org.capnproto.Text.Reader value) {
+        _setShortField(4, (short)Resp.Which.WORKER_RESTARTED.ordinal());
+        _setPointerField(org.capnproto.Text.factory, 0, value);
+      }
+      public final void setWorkerRestarted(String value) {
+        _setShortField(4, (short)Resp.Which.WORKER_RESTARTED.ordinal());
+        _setPointerField(org.capnproto.Text.factory, 0, new org.capnproto.Text.Reader(value));
+      }
+      public final org.capnproto.Text.Builder initWorkerRestarted(int size) {
+        _setShortField(4, (short)Resp.Which.WORKER_RESTARTED.ordinal());
+        return _initPointerField(org.capnproto.Text.factory, 0, size);
+      }
+      public final boolean isProgress() {
+        return which() == Resp.Which.PROGRESS;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.Progress.Builder getProgress() {
+        assert which() == Resp.Which.PROGRESS:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Progress.factory, 0, null, 0);
+      }
+      public final void setProgress(me.pepyakin.turbosolver.capnp.Api.Progress.Reader value) {
+        _setShortField(4, (short)Resp.Which.PROGRESS.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.Progress.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.Progress.Builder initProgress() {
+        _setShortField(4, (short)Resp.Which.PROGRESS.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.Progress.factory,0, 0);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.ErrorDetail.Builder getErrDetail() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.ErrorDetail.factory, 1, null, 0);
+      }
+      public final void setErrDetail(me.pepyakin.turbosolver.capnp.Api.ErrorDetail.Reader value) {
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.ErrorDetail.factory,1, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.ErrorDetail.Builder initErrDetail() {
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.ErrorDetail.factory,1, 0);
+      }
     }
 
     public static final class Reader extends org.capnproto.StructReader {
@@ -243,6 +577,9 @@ public final class Api {
         switch(_getShortField(4)) {
           case 0 : return Which.ERR;
           case 1 : return Which.OK;
+          case 2 : return Which.EVENT;
+          case 3 : return Which.WORKER_RESTARTED;
+          case 4 : return Which.PROGRESS;
           default: return Which._NOT_IN_SCHEMA;
         }
       }
@@ -271,17 +608,78 @@ public final class Api {
         return _getPointerField(me.pepyakin.turbosolver.capnp.Api.OkResp.factory,0,null, 0);
       }
 
+      public final boolean isEvent() {
+        return which() == Resp.Which.EVENT;
+      }
+      public boolean hasEvent() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.Event.Reader getEvent() {
+        assert which() == Resp.Which.EVENT:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Event.factory,0,null, 0);
+      }
+
+      public final boolean isWorkerRestarted() {
+        return which() == Resp.Which.WORKER_RESTARTED;
+      }
+      public boolean hasWorkerRestarted() {
+        if (which() != Resp.Which.WORKER_RESTARTED) return false;
+        return !_pointerFieldIsNull(0);
+      }
+      public org.capnproto.Text.Reader getWorkerRestarted() {
+        assert which() == Resp.Which.WORKER_RESTARTED:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
+      }
+
+      public final boolean isProgress() {
+        return which() == Resp.Which.PROGRESS;
+      }
+      public boolean hasProgress() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.Progress.Reader getProgress() {
+        assert which() == Resp.Which.PROGRESS:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Progress.factory,0,null, 0);
+      }
+
+      public boolean hasErrDetail() {
+        return !_pointerFieldIsNull(1);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.ErrorDetail.Reader getErrDetail() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.ErrorDetail.factory,1,null, 0);
+      }
+
     }
 
     public enum Which {
       ERR,
       OK,
+      EVENT,
+      WORKER_RESTARTED,
+      PROGRESS,
       _NOT_IN_SCHEMA,
     }
   }
 
 
-  public static class OkResp {
+  public static final int ERROR_CODES_VERSION = 1;
+
+  public enum ErrorCode {
+    INTERNAL,
+    BAD_GRID,
+    NOT_AVAILABLE,
+    SOLUTION_NOT_FOUND,
+    BAD_ASSIGNMENT,
+    QUOTA_EXCEEDED,
+    OVERLOADED,
+    BAD_MESSAGE,
+    _NOT_IN_SCHEMA,
+  }
+
+  public static class ErrorDetail {
     public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)1);
     public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
       public Factory() {
@@ -293,7 +691,7 @@ public final class Api {
         return new Builder(segment, data, pointers, dataSize, pointerCount);
       }
       public final org.capnproto.StructSize structSize() {
-        return OkResp.STRUCT_SIZE;
+        return ErrorDetail.STRUCT_SIZE;
       }
       public final Reader asReader(Builder builder) {
         return builder.asReader();
@@ -306,59 +704,29 @@ public final class Api {
       Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
         super(segment, data, pointers, dataSize, pointerCount);
       }
-      public Which which() {
-        switch(_getShortField(0)) {
-          case 0 : return Which.CREATE_SOLVER_RESP;
-          case 1 : return Which.SOLVE_RESP;
-          case 2 : return Which.DESTROY_RESP;
-          default: return Which._NOT_IN_SCHEMA;
-        }
-      }
       public final Reader asReader() {
         return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
       }
-      public final boolean isCreateSolverResp() {
-        return which() == OkResp.Which.CREATE_SOLVER_RESP;
-      }
-      public final me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.Builder getCreateSolverResp() {
-        assert which() == OkResp.Which.CREATE_SOLVER_RESP:
-                    "Must check which() before get()ing a union member.";
-        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.factory, 0, null, 0);
-      }
-      public final void setCreateSolverResp(me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.Reader value) {
-        _setShortField(0, (short)OkResp.Which.CREATE_SOLVER_RESP.ordinal());
-        _setPointerField(me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.factory,0, value);
-      }
-      public final me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.Builder initCreateSolverResp() {
-        _setShortField(0, (short)OkResp.Which.CREATE_SOLVER_RESP.ordinal());
-        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.factory,0, 0);
-      }
-      public final boolean isSolveResp() {
-        return which() == OkResp.Which.SOLVE_RESP;
+      public final boolean hasMessage() {
+        return !_pointerFieldIsNull(0);
       }
-      public final me.pepyakin.turbosolver.capnp.Api.SolveResp.Builder getSolveResp() {
-        assert which() == OkResp.Which.SOLVE_RESP:
-                    "Must check which() before get()ing a union member.";
-        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.SolveResp.factory, 0, null, 0);
+      public final org.capnproto.Text.Builder getMessage() {
+        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
       }
-      public final void setSolveResp(me.pepyakin.turbosolver.capnp.Api.SolveResp.Reader value) {
-        _setShortField(0, (short)OkResp.Which.SOLVE_RESP.ordinal());
-        _setPointerField(me.pepyakin.turbosolver.capnp.Api.SolveResp.factory,0, value);
+      public final void setMessage(org.capnproto.Text.Reader value) {
+        _setPointerField(org.capnproto.Text.factory, 0, value);
       }
-      public final me.pepyakin.turbosolver.capnp.Api.SolveResp.Builder initSolveResp() {
-        _setShortField(0, (short)OkResp.Which.SOLVE_RESP.ordinal());
-        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.SolveResp.factory,0, 0);
+      public final void setMessage(String value) {
+        _setPointerField(org.capnproto.Text.factory, 0, new org.capnproto.Text.Reader(value));
       }
-      public final boolean isDestroyResp() {
-        return which() == OkResp.Which.DESTROY_RESP;
+      public final org.capnproto.Text.Builder initMessage(int size) {
+        return _initPointerField(org.capnproto.Text.factory, 0, size);
       }
-      public final org.capnproto.Void getDestroyResp() {
-        assert which() == OkResp.Which.DESTROY_RESP:
-                    "Must check which() before get()ing a union member.";
-        return org.capnproto.Void.VOID;
+      public final int getSolverId() {
+        return _getIntField(0);
       }
-      public final void setDestroyResp(org.capnproto.Void value) {
-        _setShortField(0, (short)OkResp.Which.DESTROY_RESP.ordinal());
+      public final void setSolverId(int value) {
+        _setIntField(0, value);
       }
 
     }
@@ -368,60 +736,24 @@ public final class Api {
         super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
       }
 
-      public Which which() {
-        switch(_getShortField(0)) {
-          case 0 : return Which.CREATE_SOLVER_RESP;
-          case 1 : return Which.SOLVE_RESP;
-          case 2 : return Which.DESTROY_RESP;
-          default: return Which._NOT_IN_SCHEMA;
-        }
-      }
-      public final boolean isCreateSolverResp() {
-        return which() == OkResp.Which.CREATE_SOLVER_RESP;
-      }
-      public boolean hasCreateSolverResp() {
-        return !_pointerFieldIsNull(0);
-      }
-      public me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.Reader getCreateSolverResp() {
-        assert which() == OkResp.Which.CREATE_SOLVER_RESP:
-                    "Must check which() before get()ing a union member.";
-        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.factory,0,null, 0);
-      }
-
-      public final boolean isSolveResp() {
-        return which() == OkResp.Which.SOLVE_RESP;
-      }
-      public boolean hasSolveResp() {
+      public boolean hasMessage() {
         return !_pointerFieldIsNull(0);
       }
-      public me.pepyakin.turbosolver.capnp.Api.SolveResp.Reader getSolveResp() {
-        assert which() == OkResp.Which.SOLVE_RESP:
-                    "Must check which() before get()ing a union member.";
-        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.SolveResp.factory,0,null, 0);
+      public org.capnproto.Text.Reader getMessage() {
+        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
       }
 
-      public final boolean isDestroyResp() {
-        return which() == OkResp.Which.DESTROY_RESP;
-      }
-      public final org.capnproto.Void getDestroyResp() {
-        assert which() == OkResp.Which.DESTROY_RESP:
-                    "Must check which() before get()ing a union member.";
-        return org.capnproto.Void.VOID;
+      public final int getSolverId() {
+        return _getIntField(0);
       }
 
     }
 
-    public enum Which {
-      CREATE_SOLVER_RESP,
-      SOLVE_RESP,
-      DESTROY_RESP,
-      _NOT_IN_SCHEMA,
-    }
   }
 
 
-  public static class CreateSolverReq {
-    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)0,(short)1);
+  public static class Progress {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
     public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
       public Factory() {
       }
@@ -432,7 +764,7 @@ public final class Api {
         return new Builder(segment, data, pointers, dataSize, pointerCount);
       }
       public final org.capnproto.StructSize structSize() {
-        return CreateSolverReq.STRUCT_SIZE;
+        return Progress.STRUCT_SIZE;
       }
       public final Reader asReader(Builder builder) {
         return builder.asReader();
@@ -448,21 +780,20 @@ public final class Api {
       public final Reader asReader() {
         return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
       }
-      public final boolean hasGrid() {
-        return !_pointerFieldIsNull(0);
-      }
-      public final org.capnproto.Text.Builder getGrid() {
-        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
+      public final int getDone() {
+        return _getIntField(0);
       }
-      public final void setGrid(org.capnproto.Text.Reader value) {
-        _setPointerField(org.capnproto.Text.factory, 0, value);
+      public final void setDone(int value) {
+        _setIntField(0, value);
       }
-      public final void setGrid(String value) {
-        _setPointerField(org.capnproto.Text.factory, 0, new org.capnproto.Text.Reader(value));
+
+      public final int getTotal() {
+        return _getIntField(1);
       }
-      public final org.capnproto.Text.Builder initGrid(int size) {
-        return _initPointerField(org.capnproto.Text.factory, 0, size);
+      public final void setTotal(int value) {
+        _setIntField(1, value);
       }
+
     }
 
     public static final class Reader extends org.capnproto.StructReader {
@@ -470,11 +801,12 @@ public final class Api {
         super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
       }
 
-      public boolean hasGrid() {
-        return !_pointerFieldIsNull(0);
+      public final int getDone() {
+        return _getIntField(0);
       }
-      public org.capnproto.Text.Reader getGrid() {
-        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
+
+      public final int getTotal() {
+        return _getIntField(1);
       }
 
     }
@@ -482,8 +814,8 @@ public final class Api {
   }
 
 
-  public static class SolveReq {
-    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
+  public static class OkResp {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)1);
     public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
       public Factory() {
       }
@@ -494,7 +826,7 @@ public final class Api {
         return new Builder(segment, data, pointers, dataSize, pointerCount);
       }
       public final org.capnproto.StructSize structSize() {
-        return SolveReq.STRUCT_SIZE;
+        return OkResp.STRUCT_SIZE;
       }
       public final Reader asReader(Builder builder) {
         return builder.asReader();
@@ -507,16 +839,176 @@ public final class Api {
       Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
         super(segment, data, pointers, dataSize, pointerCount);
       }
+      public Which which() {
+        switch(_getShortField(0)) {
+          case 0 : return Which.CREATE_SOLVER_RESP;
+          case 1 : return Which.SOLVE_RESP;
+          case 2 : return Which.DESTROY_RESP;
+          case 3 : return Which.LIST_SOLVERS_RESP;
+          case 4 : return Which.DESCRIBE_SOLVER_RESP;
+          case 5 : return Which.CLONE_SOLVER_RESP;
+          case 6 : return Which.USAGE_RESP;
+          case 7 : return Which.SUBSCRIBE_EVENTS_RESP;
+          case 8 : return Which.BATCH_RESP;
+          case 9 : return Which.HANDSHAKE_RESP;
+          default: return Which._NOT_IN_SCHEMA;
+        }
+      }
       public final Reader asReader() {
         return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
       }
-      public final int getId() {
-        return _getIntField(0);
+      public final boolean isCreateSolverResp() {
+        return which() == OkResp.Which.CREATE_SOLVER_RESP;
       }
-      public final void setId(int value) {
-        _setIntField(0, value);
+      public final me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.Builder getCreateSolverResp() {
+        assert which() == OkResp.Which.CREATE_SOLVER_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.factory, 0, null, 0);
+      }
+      public final void setCreateSolverResp(me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.Reader value) {
+        _setShortField(0, (short)OkResp.Which.CREATE_SOLVER_RESP.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.Builder initCreateSolverResp() {
+        _setShortField(0, (short)OkResp.Which.CREATE_SOLVER_RESP.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.factory,0, 0);
+      }
+      public final boolean isSolveResp() {
+        return which() == OkResp.Which.SOLVE_RESP;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.SolveResp.Builder getSolveResp() {
+        assert which() == OkResp.Which.SOLVE_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.SolveResp.factory, 0, null, 0);
+      }
+      public final void setSolveResp(me.pepyakin.turbosolver.capnp.Api.SolveResp.Reader value) {
+        _setShortField(0, (short)OkResp.Which.SOLVE_RESP.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.SolveResp.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.SolveResp.Builder initSolveResp() {
+        _setShortField(0, (short)OkResp.Which.SOLVE_RESP.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.SolveResp.factory,0, 0);
+      }
+      public final boolean isDestroyResp() {
+        return which() == OkResp.Which.DESTROY_RESP;
+      }
+      public final org.capnproto.Void getDestroyResp() {
+        assert which() == OkResp.Which.DESTROY_RESP:
+                    "Must check which() before get()ing a union member.";
+        return org.capnproto.Void.VOID;
+      }
+      public final void setDestroyResp(org.capnproto.Void value) {
+        _setShortField(0, (short)OkResp.Which.DESTROY_RESP.ordinal());
+      }
+
+      public final boolean isListSolversResp() {
+        return which() == OkResp.Which.LIST_SOLVERS_RESP;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.ListSolversResp.Builder getListSolversResp() {
+        assert which() == OkResp.Which.LIST_SOLVERS_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.ListSolversResp.factory, 0, null, 0);
+      }
+      public final void setListSolversResp(me.pepyakin.turbosolver.capnp.Api.ListSolversResp.Reader value) {
+        _setShortField(0, (short)OkResp.Which.LIST_SOLVERS_RESP.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.ListSolversResp.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.ListSolversResp.Builder initListSolversResp() {
+        _setShortField(0, (short)OkResp.Which.LIST_SOLVERS_RESP.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.ListSolversResp.factory,0, 0);
+      }
+      public final boolean isDescribeSolverResp() {
+        return which() == OkResp.Which.DESCRIBE_SOLVER_RESP;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.DescribeSolverResp.Builder getDescribeSolverResp() {
+        assert which() == OkResp.Which.DESCRIBE_SOLVER_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.DescribeSolverResp.factory, 0, null, 0);
+      }
+      public final void setDescribeSolverResp(me.pepyakin.turbosolver.capnp.Api.DescribeSolverResp.Reader value) {
+        _setShortField(0, (short)OkResp.Which.DESCRIBE_SOLVER_RESP.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.DescribeSolverResp.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.DescribeSolverResp.Builder initDescribeSolverResp() {
+        _setShortField(0, (short)OkResp.Which.DESCRIBE_SOLVER_RESP.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.DescribeSolverResp.factory,0, 0);
+      }
+      public final boolean isCloneSolverResp() {
+        return which() == OkResp.Which.CLONE_SOLVER_RESP;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.CloneSolverResp.Builder getCloneSolverResp() {
+        assert which() == OkResp.Which.CLONE_SOLVER_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.CloneSolverResp.factory, 0, null, 0);
+      }
+      public final void setCloneSolverResp(me.pepyakin.turbosolver.capnp.Api.CloneSolverResp.Reader value) {
+        _setShortField(0, (short)OkResp.Which.CLONE_SOLVER_RESP.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.CloneSolverResp.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.CloneSolverResp.Builder initCloneSolverResp() {
+        _setShortField(0, (short)OkResp.Which.CLONE_SOLVER_RESP.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.CloneSolverResp.factory,0, 0);
+      }
+      public final boolean isUsageResp() {
+        return which() == OkResp.Which.USAGE_RESP;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.UsageResp.Builder getUsageResp() {
+        assert which() == OkResp.Which.USAGE_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.UsageResp.factory, 0, null, 0);
+      }
+      public final void setUsageResp(me.pepyakin.turbosolver.capnp.Api.UsageResp.Reader value) {
+        _setShortField(0, (short)OkResp.Which.USAGE_RESP.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.UsageResp.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.UsageResp.Builder initUsageResp() {
+        _setShortField(0, (short)OkResp.Which.USAGE_RESP.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.UsageResp.factory,0, 0);
+      }
+      public final boolean isSubscribeEventsResp() {
+        return which() == OkResp.Which.SUBSCRIBE_EVENTS_RESP;
+      }
+      public final org.capnproto.Void getSubscribeEventsResp() {
+        assert which() == OkResp.Which.SUBSCRIBE_EVENTS_RESP:
+                    "Must check which() before get()ing a union member.";
+        return org.capnproto.Void.VOID;
+      }
+      public final void setSubscribeEventsResp(org.capnproto.Void value) {
+        _setShortField(0, (short)OkResp.Which.SUBSCRIBE_EVENTS_RESP.ordinal());
       }
 
+      public final boolean isBatchResp() {
+        return which() == OkResp.Which.BATCH_RESP;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.BatchResp.Builder getBatchResp() {
+        assert which() == OkResp.Which.BATCH_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.BatchResp.factory, 0, null, 0);
+      }
+      public final void setBatchResp(me.pepyakin.turbosolver.capnp.Api.BatchResp.Reader value) {
+        _setShortField(0, (short)OkResp.Which.BATCH_RESP.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.BatchResp.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.BatchResp.Builder initBatchResp() {
+        _setShortField(0, (short)OkResp.Which.BATCH_RESP.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.BatchResp.factory,0, 0);
+      }
+      public final boolean isHandshakeResp() {
+        return which() == OkResp.Which.HANDSHAKE_RESP;
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.HandshakeResp.Builder getHandshakeResp() {
+        assert which() == OkResp.Which.HANDSHAKE_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.HandshakeResp.factory, 0, null, 0);
+      }
+      public final void setHandshakeResp(me.pepyakin.turbosolver.capnp.Api.HandshakeResp.Reader value) {
+        _setShortField(0, (short)OkResp.Which.HANDSHAKE_RESP.ordinal());
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.HandshakeResp.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.HandshakeResp.Builder initHandshakeResp() {
+        _setShortField(0, (short)OkResp.Which.HANDSHAKE_RESP.ordinal());
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.HandshakeResp.factory,0, 0);
+      }
     }
 
     public static final class Reader extends org.capnproto.StructReader {
@@ -524,17 +1016,155 @@ public final class Api {
         super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
       }
 
-      public final int getId() {
-        return _getIntField(0);
+      public Which which() {
+        switch(_getShortField(0)) {
+          case 0 : return Which.CREATE_SOLVER_RESP;
+          case 1 : return Which.SOLVE_RESP;
+          case 2 : return Which.DESTROY_RESP;
+          case 3 : return Which.LIST_SOLVERS_RESP;
+          case 4 : return Which.DESCRIBE_SOLVER_RESP;
+          case 5 : return Which.CLONE_SOLVER_RESP;
+          case 6 : return Which.USAGE_RESP;
+          case 7 : return Which.SUBSCRIBE_EVENTS_RESP;
+          case 8 : return Which.BATCH_RESP;
+          case 9 : return Which.HANDSHAKE_RESP;
+          default: return Which._NOT_IN_SCHEMA;
+        }
+      }
+      public final boolean isCreateSolverResp() {
+        return which() == OkResp.Which.CREATE_SOLVER_RESP;
+      }
+      public boolean hasCreateSolverResp() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.Reader getCreateSolverResp() {
+        assert which() == OkResp.Which.CREATE_SOLVER_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.CreateSolverResp.factory,0,null, 0);
+      }
+
+      public final boolean isSolveResp() {
+        return which() == OkResp.Which.SOLVE_RESP;
+      }
+      public boolean hasSolveResp() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.SolveResp.Reader getSolveResp() {
+        assert which() == OkResp.Which.SOLVE_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.SolveResp.factory,0,null, 0);
+      }
+
+      public final boolean isDestroyResp() {
+        return which() == OkResp.Which.DESTROY_RESP;
+      }
+      public final org.capnproto.Void getDestroyResp() {
+        assert which() == OkResp.Which.DESTROY_RESP:
+                    "Must check which() before get()ing a union member.";
+        return org.capnproto.Void.VOID;
+      }
+
+      public final boolean isListSolversResp() {
+        return which() == OkResp.Which.LIST_SOLVERS_RESP;
+      }
+      public boolean hasListSolversResp() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.ListSolversResp.Reader getListSolversResp() {
+        assert which() == OkResp.Which.LIST_SOLVERS_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.ListSolversResp.factory,0,null, 0);
+      }
+
+      public final boolean isDescribeSolverResp() {
+        return which() == OkResp.Which.DESCRIBE_SOLVER_RESP;
+      }
+      public boolean hasDescribeSolverResp() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.DescribeSolverResp.Reader getDescribeSolverResp() {
+        assert which() == OkResp.Which.DESCRIBE_SOLVER_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.DescribeSolverResp.factory,0,null, 0);
+      }
+
+      public final boolean isCloneSolverResp() {
+        return which() == OkResp.Which.CLONE_SOLVER_RESP;
+      }
+      public boolean hasCloneSolverResp() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.CloneSolverResp.Reader getCloneSolverResp() {
+        assert which() == OkResp.Which.CLONE_SOLVER_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.CloneSolverResp.factory,0,null, 0);
+      }
+
+      public final boolean isUsageResp() {
+        return which() == OkResp.Which.USAGE_RESP;
+      }
+      public boolean hasUsageResp() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.UsageResp.Reader getUsageResp() {
+        assert which() == OkResp.Which.USAGE_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.UsageResp.factory,0,null, 0);
+      }
+
+      public final boolean isSubscribeEventsResp() {
+        return which() == OkResp.Which.SUBSCRIBE_EVENTS_RESP;
+      }
+      public final org.capnproto.Void getSubscribeEventsResp() {
+        assert which() == OkResp.Which.SUBSCRIBE_EVENTS_RESP:
+                    "Must check which() before get()ing a union member.";
+        return org.capnproto.Void.VOID;
+      }
+
+      public final boolean isBatchResp() {
+        return which() == OkResp.Which.BATCH_RESP;
+      }
+      public boolean hasBatchResp() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.BatchResp.Reader getBatchResp() {
+        assert which() == OkResp.Which.BATCH_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.BatchResp.factory,0,null, 0);
+      }
+
+      public final boolean isHandshakeResp() {
+        return which() == OkResp.Which.HANDSHAKE_RESP;
+      }
+      public boolean hasHandshakeResp() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.HandshakeResp.Reader getHandshakeResp() {
+        assert which() == OkResp.Which.HANDSHAKE_RESP:
+                    "Must check which() before get()ing a union member.";
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.HandshakeResp.factory,0,null, 0);
       }
 
     }
 
+    public enum Which {
+      CREATE_SOLVER_RESP,
+      SOLVE_RESP,
+      DESTROY_RESP,
+      LIST_SOLVERS_RESP,
+      DESCRIBE_SOLVER_RESP,
+      CLONE_SOLVER_RESP,
+      USAGE_RESP,
+      SUBSCRIBE_EVENTS_RESP,
+      BATCH_RESP,
+      HANDSHAKE_RESP,
+      _NOT_IN_SCHEMA,
+    }
   }
 
 
-  public static class DestroyReq {
-    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
+  public static class CreateSolverReq {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)0,(short)1);
     public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
       public Factory() {
       }
@@ -545,7 +1175,7 @@ public final class Api {
         return new Builder(segment, data, pointers, dataSize, pointerCount);
       }
       public final org.capnproto.StructSize structSize() {
-        return DestroyReq.STRUCT_SIZE;
+        return CreateSolverReq.STRUCT_SIZE;
       }
       public final Reader asReader(Builder builder) {
         return builder.asReader();
@@ -561,13 +1191,21 @@ public final class Api {
       public final Reader asReader() {
         return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
       }
-      public final int getId() {
-        return _getIntField(0);
+      public final boolean hasGrid() {
+        return !_pointerFieldIsNull(0);
       }
-      public final void setId(int value) {
-        _setIntField(0, value);
+      public final org.capnproto.Text.Builder getGrid() {
+        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
+      }
+      public final void setGrid(org.capnproto.Text.Reader value) {
+        _setPointerField(org.capnproto.Text.factory, 0, value);
+      }
+      public final void setGrid(String value) {
+        _setPointerField(org.capnproto.Text.factory, 0, new org.capnproto.Text.Reader(value));
+      }
+      public final org.capnproto.Text.Builder initGrid(int size) {
+        return _initPointerField(org.capnproto.Text.factory, 0, size);
       }
-
     }
 
     public static final class Reader extends org.capnproto.StructReader {
@@ -575,8 +1213,11 @@ public final class Api {
         super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
       }
 
-      public final int getId() {
-        return _getIntField(0);
+      public boolean hasGrid() {
+        return !_pointerFieldIsNull(0);
+      }
+      public org.capnproto.Text.Reader getGrid() {
+        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
       }
 
     }
@@ -584,7 +1225,7 @@ public final class Api {
   }
 
 
-  public static class CreateSolverResp {
+  public static class SolveReq {
     public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
     public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
       public Factory() {
@@ -596,7 +1237,7 @@ public final class Api {
         return new Builder(segment, data, pointers, dataSize, pointerCount);
       }
       public final org.capnproto.StructSize structSize() {
-        return CreateSolverResp.STRUCT_SIZE;
+        return SolveReq.STRUCT_SIZE;
       }
       public final Reader asReader(Builder builder) {
         return builder.asReader();
@@ -635,8 +1276,8 @@ public final class Api {
   }
 
 
-  public static class SolveResp {
-    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)0,(short)1);
+  public static class DestroyReq {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
     public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
       public Factory() {
       }
@@ -647,7 +1288,7 @@ public final class Api {
         return new Builder(segment, data, pointers, dataSize, pointerCount);
       }
       public final org.capnproto.StructSize structSize() {
-        return SolveResp.STRUCT_SIZE;
+        return DestroyReq.STRUCT_SIZE;
       }
       public final Reader asReader(Builder builder) {
         return builder.asReader();
@@ -663,13 +1304,115 @@ public final class Api {
       public final Reader asReader() {
         return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
       }
-      public final boolean hasSolution() {
-        return !_pointerFieldIsNull(0);
+      public final int getId() {
+        return _getIntField(0);
       }
-      public final org.capnproto.Text.Builder getSolution() {
-        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
+      public final void setId(int value) {
+        _setIntField(0, value);
       }
-      public final void setSolution(org.capnproto.Text.Reader value) {
+
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final int getId() {
+        return _getIntField(0);
+      }
+
+    }
+
+  }
+
+
+  public static class CreateSolverResp {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return CreateSolverResp.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final int getId() {
+        return _getIntField(0);
+      }
+      public final void setId(int value) {
+        _setIntField(0, value);
+      }
+
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final int getId() {
+        return _getIntField(0);
+      }
+
+    }
+
+  }
+
+
+  public static class SolveResp {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)0,(short)1);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return SolveResp.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final boolean hasSolution() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.Text.Builder getSolution() {
+        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
+      }
+      public final void setSolution(org.capnproto.Text.Reader value) {
         _setPointerField(org.capnproto.Text.factory, 0, value);
       }
       public final void setSolution(String value) {
@@ -697,393 +1440,2778 @@ public final class Api {
   }
 
 
+  public static class DescribeSolverReq {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return DescribeSolverReq.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final int getId() {
+        return _getIntField(0);
+      }
+      public final void setId(int value) {
+        _setIntField(0, value);
+      }
+
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final int getId() {
+        return _getIntField(0);
+      }
+
+    }
+
+  }
+
+
+  public static class ListSolversResp {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)0,(short)1);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return ListSolversResp.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final boolean hasIds() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.PrimitiveList.Int.Builder getIds() {
+        return _getPointerField(org.capnproto.PrimitiveList.Int.factory, 0, null, 0);
+      }
+      public final void setIds(org.capnproto.PrimitiveList.Int.Reader value) {
+        _setPointerField(org.capnproto.PrimitiveList.Int.factory, 0, value);
+      }
+      public final org.capnproto.PrimitiveList.Int.Builder initIds(int size) {
+        return _initPointerField(org.capnproto.PrimitiveList.Int.factory, 0, size);
+      }
+    }
 
-public static final class Schemas {
-public static final org.capnproto.SegmentReader b_a0ddad1aa7304bce =
-   org.capnproto.GeneratedClassSupport.decodeRawBytes(
-   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
-   "\u00ce\u004b\u0030\u00a7\u001a\u00ad\u00dd\u00a0" +
-   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
-   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
-   "\u0001\u0000\u0007\u0000\u0000\u0000\u0003\u0000" +
-   "\u0002\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0015\u0000\u0000\u0000\u0072\u0000\u0000\u0000" +
-   "\u0019\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final boolean hasIds() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.PrimitiveList.Int.Reader getIds() {
+        return _getPointerField(org.capnproto.PrimitiveList.Int.factory, 0, null, 0);
+      }
+
+    }
+
+  }
+
+
+  public static class DescribeSolverResp {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)3,(short)2);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return DescribeSolverResp.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final int getId() {
+        return _getIntField(0);
+      }
+      public final void setId(int value) {
+        _setIntField(0, value);
+      }
+
+      public final boolean hasGrid() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.Text.Builder getGrid() {
+        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
+      }
+      public final void setGrid(org.capnproto.Text.Reader value) {
+        _setPointerField(org.capnproto.Text.factory, 0, value);
+      }
+      public final void setGrid(String value) {
+        _setPointerField(org.capnproto.Text.factory, 0, new org.capnproto.Text.Reader(value));
+      }
+      public final org.capnproto.Text.Builder initGrid(int size) {
+        return _initPointerField(org.capnproto.Text.factory, 0, size);
+      }
+      public final boolean hasCurrentGrid() {
+        return !_pointerFieldIsNull(1);
+      }
+      public final org.capnproto.Text.Builder getCurrentGrid() {
+        return _getPointerField(org.capnproto.Text.factory, 1, null, 0, 0);
+      }
+      public final void setCurrentGrid(org.capnproto.Text.Reader value) {
+        _setPointerField(org.capnproto.Text.factory, 1, value);
+      }
+      public final void setCurrentGrid(String value) {
+        _setPointerField(org.capnproto.Text.factory, 1, new org.capnproto.Text.Reader(value));
+      }
+      public final org.capnproto.Text.Builder initCurrentGrid(int size) {
+        return _initPointerField(org.capnproto.Text.factory, 1, size);
+      }
+      public final long getCreatedAt() {
+        return _getLongField(1);
+      }
+      public final void setCreatedAt(long value) {
+        _setLongField(1, value);
+      }
+
+      public final long getLastUsedAt() {
+        return _getLongField(2);
+      }
+      public final void setLastUsedAt(long value) {
+        _setLongField(2, value);
+      }
+
+      public final boolean getSolved() {
+        return _getBooleanField(32);
+      }
+      public final void setSolved(boolean value) {
+        _setBooleanField(32, value);
+      }
+
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final int getId() {
+        return _getIntField(0);
+      }
+
+      public boolean hasGrid() {
+        return !_pointerFieldIsNull(0);
+      }
+      public org.capnproto.Text.Reader getGrid() {
+        return _getPointerField(org.capnproto.Text.factory, 0, null, 0, 0);
+      }
+
+      public boolean hasCurrentGrid() {
+        return !_pointerFieldIsNull(1);
+      }
+      public org.capnproto.Text.Reader getCurrentGrid() {
+        return _getPointerField(org.capnproto.Text.factory, 1, null, 0, 0);
+      }
+
+      public final long getCreatedAt() {
+        return _getLongField(1);
+      }
+
+      public final long getLastUsedAt() {
+        return _getLongField(2);
+      }
+
+      public final boolean getSolved() {
+        return _getBooleanField(32);
+      }
+
+    }
+
+  }
+
+
+  public static class CloneSolverReq {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return CloneSolverReq.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final int getId() {
+        return _getIntField(0);
+      }
+      public final void setId(int value) {
+        _setIntField(0, value);
+      }
+
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final int getId() {
+        return _getIntField(0);
+      }
+
+    }
+
+  }
+
+
+  public static class SolveAssumingReq {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)1);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return SolveAssumingReq.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final int getId() {
+        return _getIntField(0);
+      }
+      public final void setId(int value) {
+        _setIntField(0, value);
+      }
+
+      public final boolean hasAssumptions() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.StructList.Builder<me.pepyakin.turbosolver.capnp.Api.Assignment.Builder> getAssumptions() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Assignment.listFactory, 0, null, 0);
+      }
+      public final void setAssumptions(org.capnproto.StructList.Reader<me.pepyakin.turbosolver.capnp.Api.Assignment.Reader> value) {
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.Assignment.listFactory, 0, value);
+      }
+      public final org.capnproto.StructList.Builder<me.pepyakin.turbosolver.capnp.Api.Assignment.Builder> initAssumptions(int size) {
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.Assignment.listFactory, 0, size);
+      }
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final int getId() {
+        return _getIntField(0);
+      }
+
+      public final boolean hasAssumptions() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.StructList.Reader<me.pepyakin.turbosolver.capnp.Api.Assignment.Reader> getAssumptions() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Assignment.listFactory, 0, null, 0);
+      }
+
+    }
+
+  }
+
+
+  public static class Assignment {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return Assignment.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final byte getRow() {
+        return _getByteField(0);
+      }
+      public final void setRow(byte value) {
+        _setByteField(0, value);
+      }
+
+      public final byte getCol() {
+        return _getByteField(1);
+      }
+      public final void setCol(byte value) {
+        _setByteField(1, value);
+      }
+
+      public final byte getDigit() {
+        return _getByteField(2);
+      }
+      public final void setDigit(byte value) {
+        _setByteField(2, value);
+      }
+
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final byte getRow() {
+        return _getByteField(0);
+      }
+
+      public final byte getCol() {
+        return _getByteField(1);
+      }
+
+      public final byte getDigit() {
+        return _getByteField(2);
+      }
+
+    }
+
+  }
+
+
+  public static class CloneSolverResp {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return CloneSolverResp.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final int getId() {
+        return _getIntField(0);
+      }
+      public final void setId(int value) {
+        _setIntField(0, value);
+      }
+
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final int getId() {
+        return _getIntField(0);
+      }
+
+    }
+
+  }
+
+
+  public static class UsageResp {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)3,(short)0);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return UsageResp.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final long getBytes() {
+        return _getLongField(0);
+      }
+      public final void setBytes(long value) {
+        _setLongField(0, value);
+      }
+
+      public final int getSolvers() {
+        return _getIntField(2);
+      }
+      public final void setSolvers(int value) {
+        _setIntField(2, value);
+      }
+
+      public final long getMaxBytes() {
+        return _getLongField(2);
+      }
+      public final void setMaxBytes(long value) {
+        _setLongField(2, value);
+      }
+
+      public final int getMaxSolvers() {
+        return _getIntField(3);
+      }
+      public final void setMaxSolvers(int value) {
+        _setIntField(3, value);
+      }
+
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final long getBytes() {
+        return _getLongField(0);
+      }
+
+      public final int getSolvers() {
+        return _getIntField(2);
+      }
+
+      public final long getMaxBytes() {
+        return _getLongField(2);
+      }
+
+      public final int getMaxSolvers() {
+        return _getIntField(3);
+      }
+
+    }
+
+  }
+
+
+  public static class BatchReq {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)1);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return BatchReq.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final boolean hasReqs() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.StructList.Builder<me.pepyakin.turbosolver.capnp.Api.Req.Builder> getReqs() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Req.listFactory, 0, null, 0);
+      }
+      public final void setReqs(org.capnproto.StructList.Reader<me.pepyakin.turbosolver.capnp.Api.Req.Reader> value) {
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.Req.listFactory, 0, value);
+      }
+      public final org.capnproto.StructList.Builder<me.pepyakin.turbosolver.capnp.Api.Req.Builder> initReqs(int size) {
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.Req.listFactory, 0, size);
+      }
+      public final boolean getStopOnError() {
+        return _getBooleanField(0);
+      }
+      public final void setStopOnError(boolean value) {
+        _setBooleanField(0, value);
+      }
+
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final boolean hasReqs() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.StructList.Reader<me.pepyakin.turbosolver.capnp.Api.Req.Reader> getReqs() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Req.listFactory, 0, null, 0);
+      }
+
+      public final boolean getStopOnError() {
+        return _getBooleanField(0);
+      }
+
+    }
+
+  }
+
+
+  public static class BatchResp {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)0,(short)1);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return BatchResp.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final boolean hasResps() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.StructList.Builder<me.pepyakin.turbosolver.capnp.Api.Resp.Builder> getResps() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Resp.listFactory, 0, null, 0);
+      }
+      public final void setResps(org.capnproto.StructList.Reader<me.pepyakin.turbosolver.capnp.Api.Resp.Reader> value) {
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.Resp.listFactory, 0, value);
+      }
+      public final org.capnproto.StructList.Builder<me.pepyakin.turbosolver.capnp.Api.Resp.Builder> initResps(int size) {
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.Resp.listFactory, 0, size);
+      }
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final boolean hasResps() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.StructList.Reader<me.pepyakin.turbosolver.capnp.Api.Resp.Reader> getResps() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Resp.listFactory, 0, null, 0);
+      }
+
+    }
+
+  }
+
+
+  public static class JournalEntry {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)2);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return JournalEntry.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final long getAt() {
+        return _getLongField(0);
+      }
+      public final void setAt(long value) {
+        _setLongField(0, value);
+      }
+
+      public final me.pepyakin.turbosolver.capnp.Api.Req.Builder getReq() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Req.factory, 0, null, 0);
+      }
+      public final void setReq(me.pepyakin.turbosolver.capnp.Api.Req.Reader value) {
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.Req.factory,0, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.Req.Builder initReq() {
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.Req.factory,0, 0);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.Resp.Builder getResp() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Resp.factory, 1, null, 0);
+      }
+      public final void setResp(me.pepyakin.turbosolver.capnp.Api.Resp.Reader value) {
+        _setPointerField(me.pepyakin.turbosolver.capnp.Api.Resp.factory,1, value);
+      }
+      public final me.pepyakin.turbosolver.capnp.Api.Resp.Builder initResp() {
+        return _initPointerField(me.pepyakin.turbosolver.capnp.Api.Resp.factory,1, 0);
+      }
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final long getAt() {
+        return _getLongField(0);
+      }
+
+      public boolean hasReq() {
+        return !_pointerFieldIsNull(0);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.Req.Reader getReq() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Req.factory,0,null, 0);
+      }
+
+      public boolean hasResp() {
+        return !_pointerFieldIsNull(1);
+      }
+      public me.pepyakin.turbosolver.capnp.Api.Resp.Reader getResp() {
+        return _getPointerField(me.pepyakin.turbosolver.capnp.Api.Resp.factory,1,null, 0);
+      }
+
+    }
+
+  }
+
+
+  public static class HandshakeReq {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)1);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return HandshakeReq.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final int getProtocolVersion() {
+        return _getIntField(0);
+      }
+      public final void setProtocolVersion(int value) {
+        _setIntField(0, value);
+      }
+
+      public final boolean hasFeatures() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.TextList.Builder getFeatures() {
+        return _getPointerField(org.capnproto.TextList.factory, 0, null, 0);
+      }
+      public final void setFeatures(org.capnproto.TextList.Reader value) {
+        _setPointerField(org.capnproto.TextList.factory, 0, value);
+      }
+      public final org.capnproto.TextList.Builder initFeatures(int size) {
+        return _initPointerField(org.capnproto.TextList.factory, 0, size);
+      }
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final int getProtocolVersion() {
+        return _getIntField(0);
+      }
+
+      public final boolean hasFeatures() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.TextList.Reader getFeatures() {
+        return _getPointerField(org.capnproto.TextList.factory, 0, null, 0);
+      }
+
+    }
+
+  }
+
+
+  public static class HandshakeResp {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)1);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return HandshakeResp.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final int getProtocolVersion() {
+        return _getIntField(0);
+      }
+      public final void setProtocolVersion(int value) {
+        _setIntField(0, value);
+      }
+
+      public final int getErrorCodesVersion() {
+        return _getIntField(1);
+      }
+      public final void setErrorCodesVersion(int value) {
+        _setIntField(1, value);
+      }
+
+      public final boolean hasFeatures() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.TextList.Builder getFeatures() {
+        return _getPointerField(org.capnproto.TextList.factory, 0, null, 0);
+      }
+      public final void setFeatures(org.capnproto.TextList.Reader value) {
+        _setPointerField(org.capnproto.TextList.factory, 0, value);
+      }
+      public final org.capnproto.TextList.Builder initFeatures(int size) {
+        return _initPointerField(org.capnproto.TextList.factory, 0, size);
+      }
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final int getProtocolVersion() {
+        return _getIntField(0);
+      }
+
+      public final int getErrorCodesVersion() {
+        return _getIntField(1);
+      }
+
+      public final boolean hasFeatures() {
+        return !_pointerFieldIsNull(0);
+      }
+      public final org.capnproto.TextList.Reader getFeatures() {
+        return _getPointerField(org.capnproto.TextList.factory, 0, null, 0);
+      }
+
+    }
+
+  }
+
+
+  public static class Event {
+    public static final org.capnproto.StructSize STRUCT_SIZE = new org.capnproto.StructSize((short)1,(short)0);
+    public static final class Factory extends org.capnproto.StructFactory<Builder, Reader> {
+      public Factory() {
+      }
+      public final Reader constructReader(org.capnproto.SegmentReader segment, int data,int pointers, int dataSize, short pointerCount, int nestingLimit) {
+        return new Reader(segment,data,pointers,dataSize,pointerCount,nestingLimit);
+      }
+      public final Builder constructBuilder(org.capnproto.SegmentBuilder segment, int data,int pointers, int dataSize, short pointerCount) {
+        return new Builder(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final org.capnproto.StructSize structSize() {
+        return Event.STRUCT_SIZE;
+      }
+      public final Reader asReader(Builder builder) {
+        return builder.asReader();
+      }
+    }
+    public static final Factory factory = new Factory();
+    public static final org.capnproto.StructList.Factory<Builder,Reader> listFactory =
+      new org.capnproto.StructList.Factory<Builder, Reader>(factory);
+    public static final class Builder extends org.capnproto.StructBuilder {
+      Builder(org.capnproto.SegmentBuilder segment, int data, int pointers,int dataSize, short pointerCount){
+        super(segment, data, pointers, dataSize, pointerCount);
+      }
+      public final Reader asReader() {
+        return new Reader(segment, data, pointers, dataSize, pointerCount, 0x7fffffff);
+      }
+      public final int getSolverId() {
+        return _getIntField(0);
+      }
+      public final void setSolverId(int value) {
+        _setIntField(0, value);
+      }
+
+      public final me.pepyakin.turbosolver.capnp.Api.EventKind getKind() {
+        switch(_getShortField(2)) {
+          case 0 : return me.pepyakin.turbosolver.capnp.Api.EventKind.CREATED;
+          case 1 : return me.pepyakin.turbosolver.capnp.Api.EventKind.SOLVED;
+          case 2 : return me.pepyakin.turbosolver.capnp.Api.EventKind.DESTROYED;
+          default: return me.pepyakin.turbosolver.capnp.Api.EventKind._NOT_IN_SCHEMA;
+        }
+      }
+      public final void setKind(me.pepyakin.turbosolver.capnp.Api.EventKind value) {
+        _setShortField(2, (short)value.ordinal());
+      }
+
+    }
+
+    public static final class Reader extends org.capnproto.StructReader {
+      Reader(org.capnproto.SegmentReader segment, int data, int pointers,int dataSize, short pointerCount, int nestingLimit){
+        super(segment, data, pointers, dataSize, pointerCount, nestingLimit);
+      }
+
+      public final int getSolverId() {
+        return _getIntField(0);
+      }
+
+      public final me.pepyakin.turbosolver.capnp.Api.EventKind getKind() {
+        switch(_getShortField(2)) {
+          case 0 : return me.pepyakin.turbosolver.capnp.Api.EventKind.CREATED;
+          case 1 : return me.pepyakin.turbosolver.capnp.Api.EventKind.SOLVED;
+          case 2 : return me.pepyakin.turbosolver.capnp.Api.EventKind.DESTROYED;
+          default: return me.pepyakin.turbosolver.capnp.Api.EventKind._NOT_IN_SCHEMA;
+        }
+      }
+
+    }
+
+  }
+
+
+  public enum EventKind {
+    CREATED,
+    SOLVED,
+    DESTROYED,
+    _NOT_IN_SCHEMA,
+  }
+
+
+public static final class Schemas {
+public static final org.capnproto.SegmentReader b_a0ddad1aa7304bce =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u00ce\u004b\u0030\u00a7\u001a\u00ad\u00dd\u00a0" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0002\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0001\u0000\u0007\u0000\u0000\u0000\u000b\u0000" +
+   "\u0002\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u0072\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u0017\u0003\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0052\u0065\u0071\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0038\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0079\u0001\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0074\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0080\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u00ff\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u007d\u0001\u0000\u0000\u0082\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u007c\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0088\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0002\u0000\u00fe\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0002\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0085\u0001\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0084\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0090\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0003\u0000\u00fd\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0003\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u008d\u0001\u0000\u0000\u005a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u008c\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0098\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0004\u0000\u00fc\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0004\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0095\u0001\u0000\u0000\u007a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0094\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00a0\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0005\u0000\u00fb\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0005\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u009d\u0001\u0000\u0000\u0092\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00a0\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00ac\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0006\u0000\u00fa\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0006\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00a9\u0001\u0000\u0000\u007a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00a8\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00b4\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0007\u0000\u00f9\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b1\u0001\u0000\u0000\u008a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b4\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00c0\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0008\u0000\u00f8\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0008\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00bd\u0001\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00bc\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00c8\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0009\u0000\u00f7\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0009\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00c5\u0001\u0000\u0000\u009a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00c8\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00d4\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u000c\u0000\u0000\u0000\u0003\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\n\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00d1\u0001\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00d0\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00dc\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\r\u0000\u0000\u0000\u0040\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u000b\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00d9\u0001\u0000\u0000\u007a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00d8\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00e4\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\n\u0000\u00f6\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u000c\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00e1\u0001\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00e0\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00ec\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u000b\u0000\u00f5\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\r\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00e9\u0001\u0000\u0000\u006a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00e8\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00f4\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0063\u0072\u0065\u0061\u0074\u0065\u0053\u006f" +
+   "\u006c\u0076\u0065\u0072\u0052\u0065\u0071\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00e9\u0082\u000b\u0032\u00a4\u001b\u008d\u00f9" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0073\u006f\u006c\u0076\u0065\u0052\u0065\u0071" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0006\u00fb\u005f\u00e2\u0032\u005b\u00b5\u00ff" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0064\u0065\u0073\u0074\u0072\u006f\u0079\u0052" +
+   "\u0065\u0071\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00a8\u008b\u0093\u0082\u0086\u00ba\u00b3\u00a5" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u006c\u0069\u0073\u0074\u0053\u006f\u006c\u0076" +
+   "\u0065\u0072\u0073\u0052\u0065\u0071\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0064\u0065\u0073\u0063\u0072\u0069\u0062\u0065" +
+   "\u0053\u006f\u006c\u0076\u0065\u0072\u0052\u0065" +
+   "\u0071\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u00fb\u0085\u0095\\\u0035\u0074\u00d8" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0063\u006c\u006f\u006e\u0065\u0053\u006f\u006c" +
+   "\u0076\u0065\u0072\u0052\u0065\u0071\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0096\u00b7\u00ef\u00cf\u0085\u0096\u007c\u00d4" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0073\u006f\u006c\u0076\u0065\u0041\u0073\u0073" +
+   "\u0075\u006d\u0069\u006e\u0067\u0052\u0065\u0071" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0030\u000e\u00e8\u00d9\u00d5\u006c\u0085\u00ba" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0075\u0073\u0061\u0067\u0065\u0052\u0065\u0071" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0073\u0075\u0062\u0073\u0063\u0072\u0069\u0062" +
+   "\u0065\u0045\u0076\u0065\u006e\u0074\u0073\u0052" +
+   "\u0065\u0071\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0070\u0072\u0069\u006f\u0072\u0069\u0074\u0079" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000f\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u006a\u00e2\u00ef\u00e3\u009b\u001c\u0043\u008a" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000f\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0072\u0065\u0070\u006f\u0072\u0074\u0050\u0072" +
+   "\u006f\u0067\u0072\u0065\u0073\u0073\u0000\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0062\u0061\u0074\u0063\u0068\u0052\u0065\u0071" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0095\u0088\u005b\u00ce\u0010\u00f8\u0092\u0092" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0068\u0061\u006e\u0064\u0073\u0068\u0061\u006b" +
+   "\u0065\u0052\u0065\u0071\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0041\u00ff\u00d5\u0041\u0038\u0031\u00df\u008f" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_8a431c9be3efe26a =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u006a\u00e2\u00ef\u00e3\u009b\u001c\u0043\u008a" +
+   "\n\u0000\u0000\u0000\u0002\u0000\u0000\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u009a\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u004f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0050\u0072\u0069\u006f\u0072\u0069" +
+   "\u0074\u0079\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u000c\u0000\u0000\u0000\u0001\u0000\u0002\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u003a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\"\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0002\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u002a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u006e\u006f\u0072\u006d\u0061\u006c\u0000\u0000" +
+   "\u006c\u006f\u0077\u0000\u0000\u0000\u0000\u0000" +
+   "\u0068\u0069\u0067\u0068\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_811606fa70edd713 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u0013\u00d7\u00ed\u0070\u00fa\u0006\u0016\u0081" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0002\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0002\u0000\u0007\u0000\u0000\u0000\u0005\u0000" +
+   "\u0004\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u007a\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u008f\u0001\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0052\u0065\u0073\u0070\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u001c\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b5\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b0\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00bc\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u00ff\u00ff\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b9\u0000\u0000\u0000\"\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b4\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00c0\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0002\u0000\u00fe\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0002\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00bd\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b8\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00c4\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0003\u0000\u00fd\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0003\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00c1\u0000\u0000\u0000\u0032\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00bc\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00c8\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0004\u0000\u00fc\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0004\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00c5\u0000\u0000\u0000\u0082\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00c4\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00d0\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0005\u0000\u00fb\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0005\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00cd\u0000\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00cc\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00d8\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0006\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0006\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00d5\u0000\u0000\u0000\u0052\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00d4\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00e0\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0065\u0072\u0072\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u006f\u006b\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00c7\u0060\u00ae\u0038\u0010\u00fc\u003a\u00b1" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0065\u0076\u0065\u006e\u0074\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0044\u00c6\u002d\u00d5\u00a4\u0065\u00c5\u0095" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0077\u006f\u0072\u006b\u0065\u0072\u0052\u0065" +
+   "\u0073\u0074\u0061\u0072\u0074\u0065\u0064\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0070\u0072\u006f\u0067\u0072\u0065\u0073\u0073" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00f6\u002e\u00c3\u0096\u00cd\u00ab\u0061\u00dc" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0065\u0072\u0072\u0044\u0065\u0074\u0061\u0069" +
+   "\u006c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0017\u0034\u00d7\u00ca\u0016\u0071\r\u00e7" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_b8d8f8d85523612a =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u002a\u0061\u0023\u0055\u00d8\u00f8\u00d8\u00b8" +
+   "\n\u0000\u0000\u0000\u0004\u0000\u0000\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00e2\u0000\u0000\u0000" +
+   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001c\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0028\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0065\u0072\u0072\u006f\u0072\u0043" +
+   "\u006f\u0064\u0065\u0073\u0056\u0065\u0072\u0073" +
+   "\u0069\u006f\u006e\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_a180e80b9ee1bcba =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u00ba\u00bc\u00e1\u009e\u000b\u00e8\u0080\u00a1" +
+   "\n\u0000\u0000\u0000\u0002\u0000\u0000\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00a2\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u00c7\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0045\u0072\u0072\u006f\u0072\u0043" +
+   "\u006f\u0064\u0065\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0020\u0000\u0000\u0000\u0001\u0000\u0002\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0059\u0000\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0055\u0000\u0000\u0000\u0042\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0002\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u004d\u0000\u0000\u0000\u006a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0003\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0049\u0000\u0000\u0000\u008a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0004\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0049\u0000\u0000\u0000\u0072\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0005\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0045\u0000\u0000\u0000\u0072\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0006\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0041\u0000\u0000\u0000\u005a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0007\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u003d\u0000\u0000\u0000\u005a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0069\u006e\u0074\u0065\u0072\u006e\u0061\u006c" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0062\u0061\u0064\u0047\u0072\u0069\u0064\u0000" +
+   "\u006e\u006f\u0074\u0041\u0076\u0061\u0069\u006c" +
+   "\u0061\u0062\u006c\u0065\u0000\u0000\u0000\u0000" +
+   "\u0073\u006f\u006c\u0075\u0074\u0069\u006f\u006e" +
+   "\u004e\u006f\u0074\u0046\u006f\u0075\u006e\u0064" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0062\u0061\u0064\u0041\u0073\u0073\u0069\u0067" +
+   "\u006e\u006d\u0065\u006e\u0074\u0000\u0000\u0000" +
+   "\u0071\u0075\u006f\u0074\u0061\u0045\u0078\u0063" +
+   "\u0065\u0065\u0064\u0065\u0064\u0000\u0000\u0000" +
+   "\u006f\u0076\u0065\u0072\u006c\u006f\u0061\u0064" +
+   "\u0065\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0062\u0061\u0064\u004d\u0065\u0073\u0073\u0061" +
+   "\u0067\u0065\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_e70d7116cad73417 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u0017\u0034\u00d7\u00ca\u0016\u0071\r\u00e7" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0001\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00b2\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u0077\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0045\u0072\u0072\u006f\u0072\u0044" +
+   "\u0065\u0074\u0061\u0069\u006c\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0029\u0000\u0000\u0000\u0042\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0024\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0030\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u002d\u0000\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u002c\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0038\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u006d\u0065\u0073\u0073\u0061\u0067\u0065\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0073\u006f\u006c\u0076\u0065\u0072\u0049\u0064" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_dc61abcd96c32ef6 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u00f6\u002e\u00c3\u0096\u00cd\u00ab\u0061\u00dc" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u009a\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u0077\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0050\u0072\u006f\u0067\u0072\u0065" +
+   "\u0073\u0073\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0029\u0000\u0000\u0000\u002a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0024\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0030\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u002d\u0000\u0000\u0000\u0032\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0028\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0034\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0064\u006f\u006e\u0065\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0074\u006f\u0074\u0061\u006c\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_b13afc1038ae60c7 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u00c7\u0060\u00ae\u0038\u0010\u00fc\u003a\u00b1" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0001\u0000\u0007\u0000\u0000\u0000\n\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u008a\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u0037\u0002\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u004f\u006b\u0052\u0065\u0073\u0070" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0028\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u00ff\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0009\u0001\u0000\u0000\u008a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0018\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u00fe\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0001\u0000\u0000\u0052\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0014\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0020\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0002\u0000\u00fd\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0002\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0001\u0000\u0000\u0062\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001c\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0028\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0003\u0000\u00fc\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0003\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0025\u0001\u0000\u0000\u0082\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0024\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0030\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0004\u0000\u00fb\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0004\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u002d\u0001\u0000\u0000\u009a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0030\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u003c\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0005\u0000\u00fa\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0005\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0039\u0001\u0000\u0000\u0082\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0038\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0044\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0006\u0000\u00f9\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0006\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0041\u0001\u0000\u0000\u0052\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0040\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u004c\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0007\u0000\u00f8\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0049\u0001\u0000\u0000\u00a2\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u004c\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0058\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0008\u0000\u00f7\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0008\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0055\u0001\u0000\u0000\u0052\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0054\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0060\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0009\u0000\u00f6\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0009\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u005d\u0001\u0000\u0000\u0072\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\\\u0001\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0068\u0001\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0063\u0072\u0065\u0061\u0074\u0065\u0053\u006f" +
+   "\u006c\u0076\u0065\u0072\u0052\u0065\u0073\u0070" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00db\u008f\u009c\u008e\"\u008e\u00d1\u008f" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0073\u006f\u006c\u0076\u0065\u0052\u0065\u0073" +
+   "\u0070\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u004c\u0049\u004b\u006f\u00be\u00bf\u008e" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0064\u0065\u0073\u0074\u0072\u006f\u0079\u0052" +
+   "\u0065\u0073\u0070\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u006c\u0069\u0073\u0074\u0053\u006f\u006c\u0076" +
+   "\u0065\u0072\u0073\u0052\u0065\u0073\u0070\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0081\u00c5\u008f\u0088\u0020\u0013\u00e0\u00a6" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0064\u0065\u0073\u0063\u0072\u0069\u0062\u0065" +
+   "\u0053\u006f\u006c\u0076\u0065\u0072\u0052\u0065" +
+   "\u0073\u0070\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00c8\u0080\u0087\u0070\u0031\u0060\u0079\u00dd" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0063\u006c\u006f\u006e\u0065\u0053\u006f\u006c" +
+   "\u0076\u0065\u0072\u0052\u0065\u0073\u0070\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u006f\u00f4\u008b\u0039\u0066\u007b\u0043\u00b0" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0075\u0073\u0061\u0067\u0065\u0052\u0065\u0073" +
+   "\u0070\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u006e\u00ae\u00bb\u00f7\u00d5\u0091\\\u00f5" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0073\u0075\u0062\u0073\u0063\u0072\u0069\u0062" +
+   "\u0065\u0045\u0076\u0065\u006e\u0074\u0073\u0052" +
+   "\u0065\u0073\u0070\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0062\u0061\u0074\u0063\u0068\u0052\u0065\u0073" +
+   "\u0070\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u009e\u00e6\u00f9\u0012\u007e\u00a1\u009b\u0081" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0068\u0061\u006e\u0064\u0073\u0068\u0061\u006b" +
+   "\u0065\u0052\u0065\u0073\u0070\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00fb\u0051\u0093\u007f\u00a6\u00cf\u0088\u00e2" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_f98d1ba4320b82e9 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u00e9\u0082\u000b\u0032\u00a4\u001b\u008d\u00f9" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0001\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00d2\u0000\u0000\u0000" +
+   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0043\u0072\u0065\u0061\u0074\u0065" +
+   "\u0053\u006f\u006c\u0076\u0065\u0072\u0052\u0065" +
+   "\u0071\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u002a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0067\u0072\u0069\u0064\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_ffb55b32e25ffb06 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u0006\u00fb\u005f\u00e2\u0032\u005b\u00b5\u00ff" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u009a\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0053\u006f\u006c\u0076\u0065\u0052" +
+   "\u0065\u0071\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_a5b3ba8682938ba8 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u00a8\u008b\u0093\u0082\u0086\u00ba\u00b3\u00a5" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00aa\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0044\u0065\u0073\u0074\u0072\u006f" +
+   "\u0079\u0052\u0065\u0071\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_8fd18e228e9c8fdb =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u00db\u008f\u009c\u008e\"\u008e\u00d1\u008f" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00da\u0000\u0000\u0000" +
+   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0043\u0072\u0065\u0061\u0074\u0065" +
+   "\u0053\u006f\u006c\u0076\u0065\u0072\u0052\u0065" +
+   "\u0073\u0070\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_8ebfbe6f4b494c10 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u0010\u004c\u0049\u004b\u006f\u00be\u00bf\u008e" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0001\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00a2\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0053\u006f\u006c\u0076\u0065\u0052" +
+   "\u0065\u0073\u0070\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0018\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0073\u006f\u006c\u0075\u0074\u0069\u006f\u006e" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_d874355c9585fb1d =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u001d\u00fb\u0085\u0095\\\u0035\u0074\u00d8" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00e2\u0000\u0000\u0000" +
+   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0044\u0065\u0073\u0063\u0072\u0069" +
+   "\u0062\u0065\u0053\u006f\u006c\u0076\u0065\u0072" +
+   "\u0052\u0065\u0071\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_a6e01320888fc581 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u0081\u00c5\u008f\u0088\u0020\u0013\u00e0\u00a6" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0001\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00d2\u0000\u0000\u0000" +
+   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u004c\u0069\u0073\u0074\u0053\u006f" +
+   "\u006c\u0076\u0065\u0072\u0073\u0052\u0065\u0073" +
+   "\u0070\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\"\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0024\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0073\u0000\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_dd796031708780c8 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u00c8\u0080\u0087\u0070\u0031\u0060\u0079\u00dd" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0003\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0002\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00ea\u0000\u0000\u0000" +
+   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0057\u0001\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0044\u0065\u0073\u0063\u0072\u0069" +
+   "\u0062\u0065\u0053\u006f\u006c\u0076\u0065\u0072" +
+   "\u0052\u0065\u0073\u0070\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0018\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0099\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0094\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00a0\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u009d\u0000\u0000\u0000\u002a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0098\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00a4\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0002\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0002\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00a1\u0000\u0000\u0000\u0062\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00a0\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00ac\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0003\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0003\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00a9\u0000\u0000\u0000\u0052\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00a8\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00b4\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0002\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0004\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b1\u0000\u0000\u0000\u005a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b0\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00bc\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0005\u0000\u0000\u0000\u0020\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0005\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b9\u0000\u0000\u0000\u003a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00b4\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u00c0\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0067\u0072\u0069\u0064\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0063\u0075\u0072\u0072\u0065\u006e\u0074\u0047" +
+   "\u0072\u0069\u0064\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0063\u0072\u0065\u0061\u0074\u0065\u0064\u0041" +
+   "\u0074\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0009\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0009\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u006c\u0061\u0073\u0074\u0055\u0073\u0065\u0064" +
+   "\u0041\u0074\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0009\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0009\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0073\u006f\u006c\u0076\u0065\u0064\u0000\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_d47c9685cfefb796 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u0096\u00b7\u00ef\u00cf\u0085\u0096\u007c\u00d4" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00ca\u0000\u0000\u0000" +
+   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0043\u006c\u006f\u006e\u0065\u0053" +
+   "\u006f\u006c\u0076\u0065\u0072\u0052\u0065\u0071" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_ba856cd5d9e80e30 =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u0030\u000e\u00e8\u00d9\u00d5\u006c\u0085\u00ba" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0001\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00da\u0000\u0000\u0000" +
+   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0077\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0053\u006f\u006c\u0076\u0065\u0041" +
+   "\u0073\u0073\u0075\u006d\u0069\u006e\u0067\u0052" +
+   "\u0065\u0071\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0029\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0024\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0030\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u002d\u0000\u0000\u0000\u0062\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u002c\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0048\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0073\u0073\u0075\u006d\u0070\u0074\u0069" +
+   "\u006f\u006e\u0073\u0000\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00ae\u003b\u0035\u0076\u00dc\u00f5\u00a9\u0085" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_85a9f5dc76353bae =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u00ae\u003b\u0035\u0076\u00dc\u00f5\u00a9\u0085" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00aa\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u00af\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0041\u0073\u0073\u0069\u0067\u006e" +
+   "\u006d\u0065\u006e\u0074\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u000c\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0045\u0000\u0000\u0000\"\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0040\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u004c\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0049\u0000\u0000\u0000\"\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0044\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0050\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0002\u0000\u0000\u0000\u0002\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0002\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u004d\u0000\u0000\u0000\u0032\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0048\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0054\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0072\u006f\u0077\u0000\u0000\u0000\u0000\u0000" +
+   "\u0006\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0006\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0063\u006f\u006c\u0000\u0000\u0000\u0000\u0000" +
+   "\u0006\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0006\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0064\u0069\u0067\u0069\u0074\u0000\u0000\u0000" +
+   "\u0006\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0006\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_b0437b66398bf46f =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u006f\u00f4\u008b\u0039\u0066\u007b\u0043\u00b0" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00d2\u0000\u0000\u0000" +
+   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0043\u006c\u006f\u006e\u0065\u0053" +
+   "\u006f\u006c\u0076\u0065\u0072\u0052\u0065\u0073" +
+   "\u0070\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_f55c91d5f7bbae6e =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u006e\u00ae\u00bb\u00f7\u00d5\u0091\\\u00f5" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0003\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00a2\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0015\u0000\u0000\u0000\u00e7\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u00e7\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
-   "\u0070\u003a\u0052\u0065\u0071\u0000\u0000\u0000" +
+   "\u0070\u003a\u0055\u0073\u0061\u0067\u0065\u0052" +
+   "\u0065\u0073\u0070\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
    "\u0010\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0061\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0061\u0000\u0000\u0000\u0032\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\\\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
    "\u0068\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0001\u0000\u00ff\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0001\u0000\u0000\u0000\u0002\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0065\u0000\u0000\u0000\u0082\u0000\u0000\u0000" +
+   "\u0065\u0000\u0000\u0000\u0042\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0064\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0070\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0002\u0000\u00fe\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0060\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u006c\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0002\u0000\u0000\u0000\u0002\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0002\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u006d\u0000\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0069\u0000\u0000\u0000\u004a\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u006c\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0078\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0003\u0000\u00fd\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0068\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0074\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0003\u0000\u0000\u0000\u0003\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0003\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0075\u0000\u0000\u0000\u005a\u0000\u0000\u0000" +
+   "\u0071\u0000\u0000\u0000\u005a\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0074\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0080\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0070\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u007c\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0062\u0079\u0074\u0065\u0073\u0000\u0000\u0000" +
+   "\u0009\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0009\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0073\u006f\u006c\u0076\u0065\u0072\u0073\u0000" +
    "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0063\u0072\u0065\u0061\u0074\u0065\u0053\u006f" +
-   "\u006c\u0076\u0065\u0072\u0052\u0065\u0071\u0000" +
-   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u00e9\u0082\u000b\u0032\u00a4\u001b\u008d\u00f9" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u006d\u0061\u0078\u0042\u0079\u0074\u0065\u0073" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0073\u006f\u006c\u0076\u0065\u0052\u0065\u0071" +
+   "\u0009\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0006\u00fb\u005f\u00e2\u0032\u005b\u00b5\u00ff" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0009\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0064\u0065\u0073\u0074\u0072\u006f\u0079\u0052" +
-   "\u0065\u0071\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u00a8\u008b\u0093\u0082\u0086\u00ba\u00b3\u00a5" +
+   "\u006d\u0061\u0078\u0053\u006f\u006c\u0076\u0065" +
+   "\u0072\u0073\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
-public static final org.capnproto.SegmentReader b_811606fa70edd713 =
+public static final org.capnproto.SegmentReader b_9292f810ce5b8895 =
    org.capnproto.GeneratedClassSupport.decodeRawBytes(
    "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
-   "\u0013\u00d7\u00ed\u0070\u00fa\u0006\u0016\u0081" +
-   "\n\u0000\u0000\u0000\u0001\u0000\u0002\u0000" +
+   "\u0095\u0088\u005b\u00ce\u0010\u00f8\u0092\u0092" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
    "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
-   "\u0001\u0000\u0007\u0000\u0000\u0000\u0002\u0000" +
-   "\u0004\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0015\u0000\u0000\u0000\u007a\u0000\u0000\u0000" +
-   "\u0019\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0001\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0015\u0000\u0000\u0000\u00af\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u009a\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u0077\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
-   "\u0070\u003a\u0052\u0065\u0073\u0070\u0000\u0000" +
+   "\u0070\u003a\u0042\u0061\u0074\u0063\u0068\u0052" +
+   "\u0065\u0071\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
-   "\u000c\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0045\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0029\u0000\u0000\u0000\u002a\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0040\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u004c\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0001\u0000\u00ff\u00ff\u0001\u0000\u0000\u0000" +
+   "\u0024\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0040\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0049\u0000\u0000\u0000\"\u0000\u0000\u0000" +
+   "\u003d\u0000\u0000\u0000\u0062\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0044\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0050\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0002\u0000\u00fe\u00ff\u0000\u0000\u0000\u0000" +
-   "\u0000\u0000\u0001\u0000\u0002\u0000\u0000\u0000" +
+   "\u003c\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0048\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0072\u0065\u0071\u0073\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u004d\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0048\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0054\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u00ce\u004b\u0030\u00a7\u001a\u00ad\u00dd\u00a0" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0073\u0074\u006f\u0070\u004f\u006e\u0045\u0072" +
+   "\u0072\u006f\u0072\u0000\u0000\u0000\u0000\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0065\u0072\u0072\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+public static final org.capnproto.SegmentReader b_819ba17e12f9e69e =
+   org.capnproto.GeneratedClassSupport.decodeRawBytes(
+   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
+   "\u009e\u00e6\u00f9\u0012\u007e\u00a1\u009b\u0081" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
+   "\u0001\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00a2\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u006f\u006b\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u00c7\u0060\u00ae\u0038\u0010\u00fc\u003a\u00b1" +
+   "\u0019\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
+   "\u0070\u003a\u0042\u0061\u0074\u0063\u0068\u0052" +
+   "\u0065\u0073\u0070\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
+   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u0032\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0024\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0072\u0065\u0073\u0070\u0073\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
    "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0013\u00d7\u00ed\u0070\u00fa\u0006\u0016\u0081" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
-public static final org.capnproto.SegmentReader b_b13afc1038ae60c7 =
+public static final org.capnproto.SegmentReader b_f66da200e23eaae8 =
    org.capnproto.GeneratedClassSupport.decodeRawBytes(
    "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
-   "\u00c7\u0060\u00ae\u0038\u0010\u00fc\u003a\u00b1" +
+   "\u00e8\u00aa\u003e\u00e2\u0000\u00a2\u006d\u00f6" +
    "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
    "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
-   "\u0001\u0000\u0007\u0000\u0000\u0000\u0003\u0000" +
+   "\u0002\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0015\u0000\u0000\u0000\u008a\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00ba\u0000\u0000\u0000" +
    "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0019\u0000\u0000\u0000\u00af\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
-   "\u0070\u003a\u004f\u006b\u0052\u0065\u0073\u0070" +
-   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0070\u003a\u004a\u006f\u0075\u0072\u006e\u0061" +
+   "\u006c\u0045\u006e\u0074\u0072\u0079\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
    "\u000c\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
-   "\u0000\u0000\u00ff\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0045\u0000\u0000\u0000\u008a\u0000\u0000\u0000" +
+   "\u0045\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0048\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0054\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0001\u0000\u00fe\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0040\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u004c\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0051\u0000\u0000\u0000\u0052\u0000\u0000\u0000" +
+   "\u0049\u0000\u0000\u0000\"\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0050\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\\\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0002\u0000\u00fd\u00ff\u0000\u0000\u0000\u0000" +
+   "\u0044\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0050\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0002\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0002\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0059\u0000\u0000\u0000\u0062\u0000\u0000\u0000" +
+   "\u004d\u0000\u0000\u0000\u002a\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0058\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0064\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0063\u0072\u0065\u0061\u0074\u0065\u0053\u006f" +
-   "\u006c\u0076\u0065\u0072\u0052\u0065\u0073\u0070" +
+   "\u0048\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0054\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0061\u0074\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0009\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u00db\u008f\u009c\u008e\"\u008e\u00d1\u008f" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0009\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0073\u006f\u006c\u0076\u0065\u0052\u0065\u0073" +
-   "\u0070\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0072\u0065\u0071\u0000\u0000\u0000\u0000\u0000" +
    "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0010\u004c\u0049\u004b\u006f\u00be\u00bf\u008e" +
+   "\u00ce\u004b\u0030\u00a7\u001a\u00ad\u00dd\u00a0" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0064\u0065\u0073\u0074\u0072\u006f\u0079\u0052" +
-   "\u0065\u0073\u0070\u0000\u0000\u0000\u0000\u0000" +
-   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0072\u0065\u0073\u0070\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0013\u00d7\u00ed\u0070\u00fa\u0006\u0016\u0081" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0010\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
-public static final org.capnproto.SegmentReader b_f98d1ba4320b82e9 =
+public static final org.capnproto.SegmentReader b_8fdf313841d5ff41 =
    org.capnproto.GeneratedClassSupport.decodeRawBytes(
    "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
-   "\u00e9\u0082\u000b\u0032\u00a4\u001b\u008d\u00f9" +
-   "\n\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u0041\u00ff\u00d5\u0041\u0038\u0031\u00df\u008f" +
+   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
    "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
    "\u0001\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0015\u0000\u0000\u0000\u00d2\u0000\u0000\u0000" +
-   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00ba\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u001d\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u0077\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
-   "\u0070\u003a\u0043\u0072\u0065\u0061\u0074\u0065" +
-   "\u0053\u006f\u006c\u0076\u0065\u0072\u0052\u0065" +
-   "\u0071\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0070\u003a\u0048\u0061\u006e\u0064\u0073\u0068" +
+   "\u0061\u006b\u0065\u0052\u0065\u0071\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
-   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\r\u0000\u0000\u0000\u002a\u0000\u0000\u0000" +
+   "\u0029\u0000\u0000\u0000\u0082\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0067\u0072\u0069\u0064\u0000\u0000\u0000\u0000" +
-   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0028\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0034\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0031\u0000\u0000\u0000\u004a\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0030\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u004c\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0070\u0072\u006f\u0074\u006f\u0063\u006f\u006c" +
+   "\u0056\u0065\u0072\u0073\u0069\u006f\u006e\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0066\u0065\u0061\u0074\u0075\u0072\u0065\u0073" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
    "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
-public static final org.capnproto.SegmentReader b_ffb55b32e25ffb06 =
+public static final org.capnproto.SegmentReader b_e288cfa67f9351fb =
    org.capnproto.GeneratedClassSupport.decodeRawBytes(
    "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
-   "\u0006\u00fb\u005f\u00e2\u0032\u005b\u00b5\u00ff" +
+   "\u00fb\u0051\u0093\u007f\u00a6\u00cf\u0088\u00e2" +
    "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
    "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
-   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0001\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0015\u0000\u0000\u0000\u009a\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u00c2\u0000\u0000\u0000" +
    "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0019\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u00af\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
-   "\u0070\u003a\u0053\u006f\u006c\u0076\u0065\u0052" +
-   "\u0065\u0071\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0070\u003a\u0048\u0061\u006e\u0064\u0073\u0068" +
+   "\u0061\u006b\u0065\u0052\u0065\u0073\u0070\u0000" +
    "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
-   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u000c\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\r\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0045\u0000\u0000\u0000\u0082\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0044\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0050\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u004d\u0000\u0000\u0000\u0092\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0050\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\\\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0002\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0002\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0059\u0000\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0058\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0074\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0070\u0072\u006f\u0074\u006f\u0063\u006f\u006c" +
+   "\u0056\u0065\u0072\u0073\u0069\u006f\u006e\u0000" +
    "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
-public static final org.capnproto.SegmentReader b_a5b3ba8682938ba8 =
-   org.capnproto.GeneratedClassSupport.decodeRawBytes(
-   "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
-   "\u00a8\u008b\u0093\u0082\u0086\u00ba\u00b3\u00a5" +
-   "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
-   "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
-   "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0015\u0000\u0000\u0000\u00aa\u0000\u0000\u0000" +
-   "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0019\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
-   "\u0070\u003a\u0044\u0065\u0073\u0074\u0072\u006f" +
-   "\u0079\u0052\u0065\u0071\u0000\u0000\u0000\u0000" +
-   "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
-   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0065\u0072\u0072\u006f\u0072\u0043\u006f\u0064" +
+   "\u0065\u0073\u0056\u0065\u0072\u0073\u0069\u006f" +
+   "\u006e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\r\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0066\u0065\u0061\u0074\u0075\u0072\u0065\u0073" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000e\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
-public static final org.capnproto.SegmentReader b_8fd18e228e9c8fdb =
+public static final org.capnproto.SegmentReader b_95c565a4d52dc644 =
    org.capnproto.GeneratedClassSupport.decodeRawBytes(
    "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
-   "\u00db\u008f\u009c\u008e\"\u008e\u00d1\u008f" +
+   "\u0044\u00c6\u002d\u00d5\u00a4\u0065\u00c5\u0095" +
    "\n\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
    "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
    "\u0000\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0015\u0000\u0000\u0000\u00da\u0000\u0000\u0000" +
-   "\u0021\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u0082\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u001d\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u0077\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
-   "\u0070\u003a\u0043\u0072\u0065\u0061\u0074\u0065" +
-   "\u0053\u006f\u006c\u0076\u0065\u0072\u0052\u0065" +
-   "\u0073\u0070\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0070\u003a\u0045\u0076\u0065\u006e\u0074\u0000" +
    "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
-   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
+   "\u0008\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\r\u0000\u0000\u0000\u001a\u0000\u0000\u0000" +
+   "\u0029\u0000\u0000\u0000\u004a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0028\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0034\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0001\u0000\u0000\u0000\u0002\u0000\u0000\u0000" +
+   "\u0000\u0000\u0001\u0000\u0001\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0031\u0000\u0000\u0000\u002a\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u002c\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
+   "\u0038\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
+   "\u0073\u006f\u006c\u0076\u0065\u0072\u0049\u0064" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0008\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0014\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0069\u0064\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0008\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u006b\u0069\u006e\u0064\u0000\u0000\u0000\u0000" +
+   "\u000f\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\\\u002f\u0075\u0028\u0087\u0068\u009e\u0084" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000f\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
-public static final org.capnproto.SegmentReader b_8ebfbe6f4b494c10 =
+public static final org.capnproto.SegmentReader b_849e688728752f5c =
    org.capnproto.GeneratedClassSupport.decodeRawBytes(
    "\u0000\u0000\u0000\u0000\u0005\u0000\u0006\u0000" +
-   "\u0010\u004c\u0049\u004b\u006f\u00be\u00bf\u008e" +
-   "\n\u0000\u0000\u0000\u0001\u0000\u0000\u0000" +
+   "\\\u002f\u0075\u0028\u0087\u0068\u009e\u0084" +
+   "\n\u0000\u0000\u0000\u0002\u0000\u0000\u0000" +
    "\u009c\u0025\u0098\u00aa\u00ea\u009a\u00f2\u00e7" +
-   "\u0001\u0000\u0007\u0000\u0000\u0000\u0000\u0000" +
+   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0015\u0000\u0000\u0000\u00a2\u0000\u0000\u0000" +
    "\u001d\u0000\u0000\u0000\u0007\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0019\u0000\u0000\u0000\u003f\u0000\u0000\u0000" +
+   "\u0019\u0000\u0000\u0000\u004f\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
    "\u0061\u0070\u0069\u002e\u0063\u0061\u0070\u006e" +
-   "\u0070\u003a\u0053\u006f\u006c\u0076\u0065\u0052" +
-   "\u0065\u0073\u0070\u0000\u0000\u0000\u0000\u0000" +
+   "\u0070\u003a\u0045\u0076\u0065\u006e\u0074\u004b" +
+   "\u0069\u006e\u0064\u0000\u0000\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0001\u0000\u0001\u0000" +
-   "\u0004\u0000\u0000\u0000\u0003\u0000\u0004\u0000" +
-   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0000\u0000\u0001\u0000\u0000\u0000\u0000\u0000" +
-   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\r\u0000\u0000\u0000\u004a\u0000\u0000\u0000" +
-   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u000c\u0000\u0000\u0000\u0003\u0000\u0001\u0000" +
-   "\u0018\u0000\u0000\u0000\u0002\u0000\u0001\u0000" +
-   "\u0073\u006f\u006c\u0075\u0074\u0069\u006f\u006e" +
-   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u000c\u0000\u0000\u0000\u0001\u0000\u0002\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u001d\u0000\u0000\u0000\u0042\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0001\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0015\u0000\u0000\u0000\u003a\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u000c\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\u0002\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
+   "\r\u0000\u0000\u0000\u0052\u0000\u0000\u0000" +
    "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" +
-   "\u0000\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
+   "\u0063\u0072\u0065\u0061\u0074\u0065\u0064\u0000" +
+   "\u0073\u006f\u006c\u0076\u0065\u0064\u0000\u0000" +
+   "\u0064\u0065\u0073\u0074\u0072\u006f\u0079\u0065" +
+   "\u0064\u0000\u0000\u0000\u0000\u0000\u0000\u0000" + "");
 }
 }
 
@@ -6,14 +6,30 @@ import io.reactivex.Single
 import io.reactivex.SingleEmitter
 import me.pepyakin.turbosolver.TurboSolver
 import me.pepyakin.turbosolver.TurboSolverFactory
+import org.capnproto.ArrayOutputStream
 import org.capnproto.MessageBuilder
 import org.capnproto.Serialize
-import java.io.ByteArrayOutputStream
 import java.nio.ByteBuffer
-import java.nio.channels.Channels
+import java.nio.ByteOrder
 import java.util.concurrent.atomic.AtomicInteger
+import java.util.concurrent.locks.ReentrantReadWriteLock
+import kotlin.concurrent.read
+import kotlin.concurrent.write
 
-class CapnpTurboSolverException(errno: Int): Exception("err: $errno")
+/**
+ * Code of an `err` response. Enumerants are generated in the order of their
+ * ordinals, codes unknown to this version are treated as internal errors.
+ */
+fun errorCode(errno: Int): Api.ErrorCode {
+    val code = Api.ErrorCode.values().getOrNull(errno)
+    return if (code == null || code == Api.ErrorCode._NOT_IN_SCHEMA) Api.ErrorCode.INTERNAL else code
+}
+
+class CapnpTurboSolverException(val errno: Int, detail: String = ""):
+        Exception("err: ${errorCode(errno)} ($errno)" + if (detail.isEmpty()) "" else ": $detail") {
+    val code: Api.ErrorCode
+        get() = errorCode(errno)
+}
 
 class CapnpTurboSolver constructor(
         private val id: Int,
@@ -55,8 +71,14 @@ class CapnpTurboSolverFactory private constructor(
 
     companion object {
         @JvmStatic
-        fun create(): CapnpTurboSolverFactory =
-                CapnpTurboSolverFactory(Dispatcher())
+        @JvmOverloads
+        fun create(workers: Int = 1): CapnpTurboSolverFactory =
+                CapnpTurboSolverFactory(Dispatcher(workers))
+
+        /** Same as [create], but limits memory and the number of solvers, `0` means unlimited. */
+        @JvmStatic
+        fun create(options: DispatcherOptions): CapnpTurboSolverFactory =
+                CapnpTurboSolverFactory(Dispatcher(options))
     }
 
     override fun create(grid: String): Single<TurboSolver> {
@@ -75,22 +97,75 @@ class CapnpTurboSolverFactory private constructor(
     }
 }
 
-class Dispatcher {
+/** Policy for requests dispatched when there are already `maxPending` of them. */
+enum class Overflow(val code: Int) {
+    /** Block [Dispatcher.dispatch] until there is space in the queue. */
+    BLOCK(0),
+    /** Fail the new request as overloaded. */
+    REJECT(1),
+    /** Fail the oldest pending request as overloaded and enqueue the new one. */
+    DROP_OLDEST(2),
+}
+
+/** Mirrors `ExecutorOptions` of the backend, `0` means unlimited. */
+data class DispatcherOptions(
+        val workers: Int = 1,
+        val maxPending: Int = 0,
+        val overflow: Overflow = Overflow.BLOCK,
+        val maxBytes: Long = 0,
+        val maxSolvers: Int = 0,
+        /** File to record the journal of requests and responses into, if any. */
+        val journalPath: String? = null)
+
+class Dispatcher(options: DispatcherOptions) {
+    constructor(workers: Int = 1) : this(DispatcherOptions(workers = workers))
+
     companion object {
         @JvmStatic
         external fun capnp_init(self: Dispatcher): Long
 
+        @JvmStatic
+        external fun capnp_init_with_workers(self: Dispatcher, workers: Int): Long
+
+        @JvmStatic
+        external fun capnp_init_with_options(
+                self: Dispatcher,
+                workers: Int,
+                maxPending: Int,
+                overflow: Int,
+                maxBytes: Long,
+                maxSolvers: Int,
+                journalPath: String?): Long
+
         @JvmStatic
         external fun capnp_send(dispatcher: Long, data: ByteArray)
 
+        /** Same as [capnp_send], but the first [len] bytes of [data] are read in place. */
+        @JvmStatic
+        external fun capnp_send_direct(dispatcher: Long, data: ByteBuffer, len: Int)
+
+        @JvmStatic
+        external fun capnp_destroy(dispatcher: Long, abort: Boolean)
+
         init {
             System.loadLibrary("solver")
         }
     }
 
-    private val executorPtr: Long
+    // Guards `executorPtr`, which is zeroed once the backend is destroyed.
+    private val executorLock = ReentrantReadWriteLock()
+    private var executorPtr: Long
     init {
-        executorPtr = capnp_init(this)
+        executorPtr = with(options) {
+            capnp_init_with_options(
+                    this@Dispatcher,
+                    workers,
+                    maxPending,
+                    overflow.code,
+                    maxBytes,
+                    maxSolvers,
+                    journalPath)
+        }
     }
 
     private var nextId = AtomicInteger()
@@ -98,6 +173,15 @@ class Dispatcher {
 
     private fun generateId(): Int = nextId.getAndIncrement()
 
+    // Requests are serialized into a per-thread direct buffer, which the backend
+    // reads in place. It's reusable as soon as `capnp_send_direct` returns.
+    private val sendBuffer = object : ThreadLocal<ByteBuffer>() {
+        override fun initialValue(): ByteBuffer = allocateSendBuffer(1024)
+    }
+
+    private fun allocateSendBuffer(size: Int): ByteBuffer =
+            ByteBuffer.allocateDirect(size).order(ByteOrder.LITTLE_ENDIAN)
+
     fun dispatch(buildMsg: Api.Req.Builder.() -> Unit): Single<Resp> {
         val reqId = generateId()
         return Single.create<Resp> { emitter ->
@@ -107,21 +191,55 @@ class Dispatcher {
                 buildMsg()
             }
 
-            // Serialize message into byte array
-            val baos = ByteArrayOutputStream()
-            val out = Channels.newChannel(baos)
-            Serialize.write(out, message)
-            val bytes = baos.toByteArray()
+            // Serialize message into the send buffer, growing it if needed.
+            val size = Serialize.computeSerializedSizeInWords(message).toInt() * 8
+            var buffer = sendBuffer.get()
+            if (buffer.capacity() < size) {
+                buffer = allocateSendBuffer(size)
+                sendBuffer.set(buffer)
+            }
+            buffer.clear()
+            Serialize.write(ArrayOutputStream(buffer), message)
+
+            executorLock.read {
+                check(executorPtr != 0L) { "dispatcher is closed" }
 
-            // Put emitter onto stash sending msg. It is important to
-            // do it before sending message to the backend.
-            synchronized(emitterStash) {
-                emitterStash.put(reqId, emitter)
+                // Put emitter onto stash sending msg. It is important to
+                // do it before sending message to the backend.
+                synchronized(emitterStash) {
+                    emitterStash.put(reqId, emitter)
+                }
+
+                // Send the message to the backend.
+                capnp_send_direct(executorPtr, buffer, size)
             }
+        }
+    }
 
-            // Send the bytes array to the backend.
-            capnp_send(executorPtr, bytes)
+    /**
+     * Shut down the backend. Unless [abort] is set, all requests dispatched so far
+     * are completed first, otherwise the pending ones fail. Must not be called from
+     * the callback thread.
+     *
+     * @throws IllegalStateException if the dispatcher is already closed. Requests
+     * dispatched afterwards fail with it as well.
+     */
+    fun close(abort: Boolean = false) {
+        // Nobody is sending at this point, and nobody will.
+        val ptr = executorLock.write {
+            check(executorPtr != 0L) { "dispatcher is closed" }
+            executorPtr.also { executorPtr = 0L }
         }
+        capnp_destroy(ptr, abort)
+
+        // No callbacks will arrive at this point.
+        val pending = synchronized(emitterStash) {
+            val emitters = (0 until emitterStash.size()).map { emitterStash.valueAt(it) }
+            emitterStash.clear()
+            emitters
+        }
+        // Subscribers might be gone already, undeliverable errors would crash the app.
+        pending.forEach { it.tryOnError(IllegalStateException("dispatcher is closed")) }
     }
 
     // Called from JNI.
@@ -145,7 +263,7 @@ class Dispatcher {
 fun Single<Resp>.extractKind(): Single<RespKind> = flatMap { resp ->
     when (resp) {
         is Resp.Ok -> Single.just(resp.respKind)
-        is Resp.Errno -> Single.error(CapnpTurboSolverException(resp.errno))
+        is Resp.Errno -> Single.error(CapnpTurboSolverException(resp.errno, resp.detail))
     }
 }
 
@@ -156,14 +274,19 @@ sealed class Resp {
                 Resp.Ok(RespKind.fromReader(reader.ok))
 
             Api.Resp.Which.ERR->
-                Resp.Errno(reader.err)
+                Resp.Errno(reader.err, reader.errDetail.message.toString())
+
+            // Unsolicited messages are never asked for.
+            Api.Resp.Which.EVENT,
+            Api.Resp.Which.WORKER_RESTARTED,
+            Api.Resp.Which.PROGRESS -> error("unexpected variant! ${reader.which()}")
 
             Api.Resp.Which._NOT_IN_SCHEMA -> error("variant not in the schema")
         }
     }
 
     data class Ok(val respKind: RespKind): Resp()
-    data class Errno(val errno: Int): Resp()
+    data class Errno(val errno: Int, val detail: String): Resp()
 }
 
 sealed class RespKind {
@@ -178,6 +301,14 @@ sealed class RespKind {
             Api.OkResp.Which.DESTROY_RESP ->
                 RespKind.SolverDestroyed
 
+            Api.OkResp.Which.LIST_SOLVERS_RESP,
+            Api.OkResp.Which.DESCRIBE_SOLVER_RESP,
+            Api.OkResp.Which.CLONE_SOLVER_RESP,
+            Api.OkResp.Which.USAGE_RESP,
+            Api.OkResp.Which.SUBSCRIBE_EVENTS_RESP,
+            Api.OkResp.Which.BATCH_RESP,
+            Api.OkResp.Which.HANDSHAKE_RESP -> error("unexpected variant! ${reader.which()}")
+
             Api.OkResp.Which._NOT_IN_SCHEMA -> error("variant not in the schema")
         }
     }
@@ -24,4 +24,5 @@ capnpc \
     --output=java:android-demo/app/src/main/java/me/pepyakin/turbosolver/capnp \
     --output=rust:libsolver/src \
     --src-prefix=common \
-    common/api.capnp
+    common/api.capnp \
+    common/rpc.capnp
@@ -0,0 +1,37 @@
+#!/bin/sh
+# Builds libsolver, compiles `ctest/test.c` against `include/turbosolver.h`
+# and runs it.
+#
+# The header is checked to be up to date with cbindgen first
+# (`cargo install cbindgen`). With `--update-header` it is regenerated instead.
+
+set -e
+
+cd "$(dirname "$0")/.."
+
+header=include/turbosolver.h
+
+if ! command -v cbindgen >/dev/null 2>&1; then
+    echo "cbindgen isn't installed. Try 'cargo install cbindgen'." >&2
+    exit 1
+fi
+
+if [ "$1" = "--update-header" ]; then
+    cbindgen --config cbindgen.toml --output "$header"
+else
+    generated=$(mktemp)
+    trap 'rm -f "$generated"' EXIT
+    cbindgen --config cbindgen.toml --output "$generated"
+    if ! diff -u "$header" "$generated"; then
+        echo "$header is out of date, run 'ctest/run.sh --update-header'." >&2
+        exit 1
+    fi
+fi
+
+cargo build
+
+out=target/debug/ctest
+cc -Wall -Wextra -Werror -std=c99 -I include ctest/test.c -L target/debug -lsolver -o "$out"
+
+LD_LIBRARY_PATH=target/debug DYLD_LIBRARY_PATH=target/debug \
+    "$out" src/sudoku.txt src/sudoku_solution.txt
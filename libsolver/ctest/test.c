@@ -0,0 +1,311 @@
+/*
+ * Exercises the C API through `include/turbosolver.h`, the way a C host
+ * would. Built and run by `ctest/run.sh`.
+ *
+ * Usage: test <grid file> <solution file>
+ */
+
+/* For `close`, which isn't part of C99. */
+#define _POSIX_C_SOURCE 200809L
+
+#include <stdio.h>
+#include <string.h>
+
+#include "turbosolver.h"
+
+#if defined(TURBOSOLVER_UNIX)
+#include <unistd.h>
+#endif
+
+static int failures = 0;
+
+#define CHECK(cond)                                                      \
+  do {                                                                   \
+    if (!(cond)) {                                                       \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__,  \
+              #cond);                                                    \
+      failures++;                                                        \
+    }                                                                    \
+  } while (0)
+
+static char *read_file(const char *path) {
+  FILE *file = fopen(path, "rb");
+  if (!file) {
+    perror(path);
+    exit(2);
+  }
+  fseek(file, 0, SEEK_END);
+  long len = ftell(file);
+  fseek(file, 0, SEEK_SET);
+  char *contents = malloc(len + 1);
+  if (fread(contents, 1, len, file) != (size_t)len) {
+    perror(path);
+    exit(2);
+  }
+  contents[len] = '\0';
+  fclose(file);
+  return contents;
+}
+
+static const char *expected;
+
+/* Results stashed by the callbacks. */
+static SolverHandle created;
+static int solved;
+static int events[3];
+
+static void on_event(uint32_t event, SolverHandle solver) {
+  (void)solver;
+  if (event < 3) {
+    events[event]++;
+  }
+}
+
+static void on_create(SolverHandle solver, const char *error) {
+  CHECK(error == NULL);
+  created = solver;
+}
+
+static void on_create_with_data(void *user_data, SolverHandle solver, const char *error) {
+  CHECK(error == NULL);
+  *(SolverHandle *)user_data = solver;
+}
+
+static void on_solve(const char *solution) {
+  CHECK(solution != NULL && strcmp(solution, expected) == 0);
+  solved++;
+}
+
+static void on_solve_with_data(void *user_data, const char *solution) {
+  CHECK(solution != NULL && strcmp(solution, expected) == 0);
+  (*(int *)user_data)++;
+}
+
+static void on_progress(uint32_t done, uint32_t total) {
+  CHECK(done <= total);
+}
+
+static void on_progress_with_data(void *user_data, uint32_t done, uint32_t total) {
+  CHECK(user_data != NULL);
+  CHECK(done <= total);
+}
+
+static void on_recv(void *user_data, const uint8_t *msg, uintptr_t msg_len) {
+  CHECK(msg != NULL && msg_len > 0);
+  (*(int *)user_data)++;
+}
+
+static int received_without_data;
+
+static void on_recv_without_data(const uint8_t *msg, uintptr_t msg_len) {
+  CHECK(msg != NULL && msg_len > 0);
+  received_without_data++;
+}
+
+static void test_abi(void) {
+  CHECK(turbosolver_abi_version() == TURBOSOLVER_ABI_VERSION);
+  CHECK(turbosolver_has_feature("handles"));
+  CHECK(turbosolver_has_feature("buffers"));
+  CHECK(turbosolver_has_feature("journal"));
+#if defined(TURBOSOLVER_UNIX)
+  CHECK(turbosolver_has_feature("rpc"));
+#else
+  CHECK(!turbosolver_has_feature("rpc"));
+#endif
+  CHECK(!turbosolver_has_feature("teleportation"));
+  CHECK(!turbosolver_has_feature(NULL));
+}
+
+static void test_callbacks(const char *grid) {
+  CHECK(solver_create(grid, on_create) == SOLVER_OK);
+  CHECK(created != 0);
+  CHECK(solver_solve(created, on_solve) == SOLVER_OK);
+  CHECK(solver_solve_with_progress(created, on_solve, on_progress) == SOLVER_OK);
+  CHECK(solved == 2);
+  CHECK(solver_destroy(created) == SOLVER_OK);
+
+  SolverHandle solver = 0;
+  int count = 0;
+  CHECK(solver_create_with_data(grid, on_create_with_data, &solver) == SOLVER_OK);
+  CHECK(solver != 0);
+  CHECK(solver_solve_with_data(solver, on_solve_with_data, &count) == SOLVER_OK);
+  CHECK(solver_solve_with_progress_and_data(solver, on_solve_with_data,
+                                            on_progress_with_data, &count) == SOLVER_OK);
+  CHECK(count == 2);
+  CHECK(solver_destroy(solver) == SOLVER_OK);
+}
+
+static void test_buffers(const char *grid) {
+  SolverHandle solver = 0;
+  CHECK(solver_create_into(grid, &solver) == SOLVER_OK);
+
+  uintptr_t needed = 0;
+  CHECK(solver_solve_into(solver, NULL, 0, &needed) == SOLVER_BUFFER_TOO_SMALL);
+  CHECK(needed == strlen(expected) + 1);
+  char *buf = malloc(needed);
+  CHECK(solver_solve_into(solver, buf, needed, &needed) == SOLVER_OK);
+  CHECK(strcmp(buf, expected) == 0);
+  free(buf);
+
+  char *solution = NULL;
+  CHECK(solver_solve_owned(solver, &solution) == SOLVER_OK);
+  CHECK(solution != NULL && strcmp(solution, expected) == 0);
+  CHECK(solver_string_free(solution) == SOLVER_OK);
+  CHECK(solver_string_free(NULL) == SOLVER_OK);
+
+  CHECK(solver_destroy(solver) == SOLVER_OK);
+}
+
+static void test_errors(void) {
+  SolverHandle solver = 0;
+  CHECK(solver_create_into("not a sudoku", &solver) == SOLVER_ERROR);
+  CHECK(solver == 0);
+  CHECK(solver_last_error_message() != NULL);
+
+  CHECK(solver_create_into(NULL, &solver) == SOLVER_INVALID_ARGUMENT);
+  CHECK(solver_solve(0, on_solve) == SOLVER_INVALID_HANDLE);
+  CHECK(strlen(solver_last_error_message()) > 0);
+}
+
+static void test_stale_handles(const char *grid) {
+  SolverHandle solver = 0;
+  CHECK(solver_create_into(grid, &solver) == SOLVER_OK);
+  CHECK(solver_destroy(solver) == SOLVER_OK);
+  CHECK(solver_destroy(solver) == SOLVER_INVALID_HANDLE);
+
+  SolverHandle reused = 0;
+  CHECK(solver_create_into(grid, &reused) == SOLVER_OK);
+  CHECK(reused != solver);
+  CHECK(solver_solve(solver, on_solve) == SOLVER_INVALID_HANDLE);
+  CHECK(solver_destroy(reused) == SOLVER_OK);
+}
+
+static void test_events(const char *grid) {
+  CHECK(solver_set_event_callback(on_event) == SOLVER_OK);
+  SolverHandle solver = 0;
+  CHECK(solver_create_into(grid, &solver) == SOLVER_OK);
+  CHECK(solver_solve(solver, on_solve) == SOLVER_OK);
+  CHECK(events[SOLVER_EVENT_SOLVED] == 1);
+
+  /* Probing for the size doesn't count as solving. */
+  uintptr_t needed = 0;
+  CHECK(solver_solve_into(solver, NULL, 0, &needed) == SOLVER_BUFFER_TOO_SMALL);
+  CHECK(events[SOLVER_EVENT_SOLVED] == 1);
+  char *buf = malloc(needed);
+  CHECK(solver_solve_into(solver, buf, needed, &needed) == SOLVER_OK);
+  CHECK(events[SOLVER_EVENT_SOLVED] == 2);
+  free(buf);
+
+  char *solution = NULL;
+  CHECK(solver_solve_owned(solver, &solution) == SOLVER_OK);
+  CHECK(events[SOLVER_EVENT_SOLVED] == 3);
+  CHECK(solver_string_free(solution) == SOLVER_OK);
+
+  CHECK(solver_destroy(solver) == SOLVER_OK);
+  CHECK(solver_set_event_callback(NULL) == SOLVER_OK);
+
+  CHECK(events[SOLVER_EVENT_CREATED] == 1);
+  CHECK(events[SOLVER_EVENT_SOLVED] == 3);
+  CHECK(events[SOLVER_EVENT_DESTROYED] == 1);
+}
+
+/* Undecodable requests are responded with an error. */
+static const uint8_t garbage[16] = {0xff, 0xff, 0xff, 0xff};
+
+static void test_capnp_init(void) {
+  void *executors[3];
+  executors[0] = capnp_init(on_recv_without_data);
+  executors[1] = capnp_init_with_workers(on_recv_without_data, 2);
+  ExecutorOptions options = {.workers = 1, .max_pending = 4, .overflow = SOLVER_OVERFLOW_REJECT};
+  executors[2] = capnp_init_with_options(on_recv_without_data, options);
+
+  for (int i = 0; i < 3; i++) {
+    CHECK(executors[i] != NULL);
+    if (executors[i] != NULL) {
+      CHECK(capnp_send(executors[i], garbage, sizeof(garbage)) == SOLVER_OK);
+      CHECK(capnp_destroy(executors[i], false) == SOLVER_OK);
+    }
+  }
+  CHECK(received_without_data == 3);
+
+  /* The journal can't be created. */
+  options.journal_path = "/nonexistent/journal";
+  CHECK(capnp_init_with_options(on_recv_without_data, options) == NULL);
+  CHECK(solver_last_error_message() != NULL);
+}
+
+static void test_capnp(void) {
+  ExecutorOptions options = {.workers = 1, .overflow = SOLVER_OVERFLOW_BLOCK};
+  int received = 0;
+  void *executor = capnp_init_with_data(on_recv, &received, options);
+  CHECK(executor != NULL);
+
+  CHECK(capnp_send(executor, garbage, sizeof(garbage)) == SOLVER_OK);
+  CHECK(capnp_send(executor, NULL, 0) == SOLVER_INVALID_ARGUMENT);
+
+  CHECK(capnp_destroy(executor, false) == SOLVER_OK);
+  CHECK(received == 1);
+
+  /* Unknown overflow policy. */
+  options.overflow = SOLVER_OVERFLOW_DROP_OLDEST + 1;
+  CHECK(capnp_init_with_data(on_recv, &received, options) == NULL);
+  CHECK(solver_last_error_message() != NULL);
+}
+
+#if defined(TURBOSOLVER_UNIX)
+static void test_rpc(void) {
+  int fd = -1;
+  CHECK(rpc_serve_pair(0, 0, &fd) == SOLVER_OK);
+  CHECK(fd >= 0);
+  /* Stops the serving thread. */
+  CHECK(close(fd) == 0);
+
+  CHECK(rpc_serve_pair(0, 0, NULL) == SOLVER_INVALID_ARGUMENT);
+  CHECK(rpc_serve_unix(NULL, 0, 0) == SOLVER_INVALID_ARGUMENT);
+}
+#endif
+
+static void test_http_symbols(void) {
+  /* These block the calling thread, so only check that they are exported.
+   * The addresses go through variables, since comparing a function itself
+   * with NULL is reported by -Waddress as always true. */
+  uint32_t (*deploy)(void) = http_deploy;
+  uint32_t (*deploy_with_workers)(uintptr_t) = http_deploy_with_workers;
+  uint32_t (*deploy_with_options)(ExecutorOptions) = http_deploy_with_options;
+  CHECK(deploy != NULL);
+  CHECK(deploy_with_workers != NULL);
+  CHECK(deploy_with_options != NULL);
+}
+
+int main(int argc, char **argv) {
+  if (argc != 3) {
+    fprintf(stderr, "usage: %s <grid file> <solution file>\n", argv[0]);
+    return 2;
+  }
+  char *grid = read_file(argv[1]);
+  char *solution = read_file(argv[2]);
+  expected = solution;
+
+  test_abi();
+  test_callbacks(grid);
+  test_buffers(grid);
+  test_errors();
+  test_stale_handles(grid);
+  test_events(grid);
+  test_capnp_init();
+  test_capnp();
+#if defined(TURBOSOLVER_UNIX)
+  test_rpc();
+#endif
+  test_http_symbols();
+
+  free(grid);
+  free(solution);
+
+  if (failures) {
+    fprintf(stderr, "%d check(s) failed\n", failures);
+    return 1;
+  }
+  printf("all checks passed\n");
+  return 0;
+}
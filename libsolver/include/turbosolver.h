@@ -0,0 +1,376 @@
+#ifndef TURBOSOLVER_H
+#define TURBOSOLVER_H
+
+/* Generated with cbindgen from the sources of libsolver, don't edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(__unix__) || defined(__APPLE__)
+#define TURBOSOLVER_UNIX
+#endif
+
+/**
+ * Status codes returned by the exported functions. On failure the
+ * description of the error is available via `solver_last_error_message`.
+ */
+#define SOLVER_OK 0
+
+/**
+ * The operation has failed, e.g. the grid couldn't be parsed.
+ */
+#define SOLVER_ERROR 1
+
+/**
+ * An argument is invalid, e.g. a null pointer.
+ */
+#define SOLVER_INVALID_ARGUMENT 2
+
+/**
+ * The library has panicked, which is a bug. Panics never cross the FFI
+ * boundary, but objects involved might be left in an unusable state.
+ */
+#define SOLVER_PANIC 3
+
+/**
+ * There is no solution, returned by functions without a callback.
+ */
+#define SOLVER_NOT_FOUND 4
+
+/**
+ * The buffer passed by the caller is too small for the result.
+ */
+#define SOLVER_BUFFER_TOO_SMALL 5
+
+/**
+ * The solver handle is invalid, e.g. it has been destroyed already.
+ */
+#define SOLVER_INVALID_HANDLE 6
+
+/**
+ * Version of the C API, bumped on changes existing callers can't cope
+ * with. Additions are discovered with `turbosolver_has_feature` instead.
+ */
+#define TURBOSOLVER_ABI_VERSION 2
+
+/**
+ * Lifecycle event codes passed to `EventCallback`.
+ *
+ * Solvers aren't evicted, they only go away with `solver_destroy`.
+ */
+#define SOLVER_EVENT_CREATED 0
+
+#define SOLVER_EVENT_SOLVED 1
+
+#define SOLVER_EVENT_DESTROYED 2
+
+/**
+ * Overflow policy codes of `ExecutorOptions`, see `Overflow`.
+ */
+#define SOLVER_OVERFLOW_BLOCK 0
+
+#define SOLVER_OVERFLOW_REJECT 1
+
+#define SOLVER_OVERFLOW_DROP_OLDEST 2
+
+/**
+ * Configuration of an executor, as passed over FFI. See `Config`.
+ *
+ * Zero means unlimited for all the limits.
+ */
+typedef struct {
+  uintptr_t workers;
+  /**
+   * Maximum number of pending requests.
+   */
+  uintptr_t max_pending;
+  /**
+   * One of `SOLVER_OVERFLOW_*` codes.
+   */
+  uint32_t overflow;
+  /**
+   * Quota on the approximate amount of memory used by solvers, in bytes.
+   */
+  uintptr_t max_bytes;
+  uintptr_t max_solvers;
+  /**
+   * Path of the file to record the journal into, see `journal::Recorder`,
+   * or null. The string is only read during initialization.
+   */
+  const char *journal_path;
+} ExecutorOptions;
+
+/**
+ * Handle of a solver, `0` is never a valid one. Handles of destroyed
+ * solvers are detected and rejected with `SOLVER_INVALID_HANDLE`, even
+ * if a new solver has been created since.
+ */
+typedef uint64_t SolverHandle;
+
+/**
+ * Callback that is called on lifecycle events of all solvers.
+ *
+ * It is called on the thread that caused the event. On `SOLVER_EVENT_DESTROYED`
+ * the handle is already invalid.
+ */
+typedef void (*EventCallback)(uint32_t event, SolverHandle solver);
+
+/**
+ * Callback that is called with the new solver, or with the description
+ * of the error if it couldn't be created.
+ */
+typedef void (*CreateCallback)(SolverHandle solver, const char *error);
+
+/**
+ * Same as `CreateCallback`, with the `user_data` passed to `solver_create_with_data`.
+ */
+typedef void (*CreateCallbackWithData)(void *user_data, SolverHandle solver, const char *error);
+
+/**
+ * Callback that is called with the solution, or null if there is none.
+ */
+typedef void (*SolveCallback)(const char *solution);
+
+/**
+ * Same as `SolveCallback`, with the `user_data` passed to `solver_solve_with_data`.
+ */
+typedef void (*SolveCallbackWithData)(void *user_data, const char *solution);
+
+/**
+ * Callback that is called with progress of a long-running operation:
+ * `done` out of `total` steps.
+ */
+typedef void (*ProgressCallback)(uint32_t done, uint32_t total);
+
+/**
+ * Same as `ProgressCallback`, with the `user_data` passed along with it.
+ */
+typedef void (*ProgressCallbackWithData)(void *user_data, uint32_t done, uint32_t total);
+
+/**
+ * Callback receiving encoded responses and events. The message is valid
+ * only during the call.
+ */
+typedef void (*RecvCallback)(const uint8_t *msg, uintptr_t msg_len);
+
+/**
+ * Same as `RecvCallback`, with the `user_data` passed to `capnp_init_with_data`.
+ */
+typedef void (*RecvCallbackWithData)(void *user_data, const uint8_t *msg, uintptr_t msg_len);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Version of the C API of the loaded library. Hosts should check it
+ * against `TURBOSOLVER_ABI_VERSION` of the header they were built with.
+ */
+uint32_t turbosolver_abi_version(void);
+
+/**
+ * Whether the loaded library supports the optional `feature`, e.g.
+ * `"buffers"` for `solver_solve_into` and friends. Unknown features and
+ * null are reported as unsupported.
+ */
+bool turbosolver_has_feature(const char *feature);
+
+/**
+ * Description of the last error on the calling thread, or null if
+ * nothing has failed on it yet.
+ *
+ * Only meaningful right after a function returned a status other than
+ * `SOLVER_OK`, or null where a pointer was expected, since successful
+ * calls don't reset it. The string is valid until the next failure on
+ * the same thread.
+ */
+const char *solver_last_error_message(void);
+
+/**
+ * Register `callback` to be called on lifecycle events, replacing the previous one.
+ * Pass null to unregister.
+ */
+uint32_t solver_set_event_callback(EventCallback callback);
+
+/**
+ * Parse `sudoku_grid` and pass the new solver to `f`, or `0` and the
+ * description of the error if it couldn't be parsed. Returns `SOLVER_ERROR` in the
+ * latter case, and `SOLVER_INVALID_ARGUMENT` without calling `f` if
+ * `sudoku_grid` is null.
+ */
+uint32_t solver_create(const char *sudoku_grid, CreateCallback f);
+
+/**
+ * Same as `solver_create`, but `user_data` is passed to `f` as is, e.g. to
+ * route the result back to the object that made the call.
+ */
+uint32_t solver_create_with_data(const char *sudoku_grid,
+                                 CreateCallbackWithData f,
+                                 void *user_data);
+
+/**
+ * Same as `solver_create`, but the new solver is stored into `solver`
+ * instead of being passed to a callback. The description of the error
+ * is available via `solver_last_error_message`.
+ */
+uint32_t solver_create_into(const char *sudoku_grid, SolverHandle *solver);
+
+/**
+ * Solve `solver` and pass the solution to `f`, or null if there is none.
+ * Not finding a solution isn't a failure of the call.
+ */
+uint32_t solver_solve(SolverHandle solver, SolveCallback f);
+
+/**
+ * Same as `solver_solve`, but `user_data` is passed to `f` as is.
+ */
+uint32_t solver_solve_with_data(SolverHandle solver, SolveCallbackWithData f, void *user_data);
+
+/**
+ * Same as `solver_solve`, but `progress` is called with progress of
+ * solving, before `f` is called. `progress` must not use `solver`.
+ */
+uint32_t solver_solve_with_progress(SolverHandle solver,
+                                    SolveCallback f,
+                                    ProgressCallback progress);
+
+/**
+ * Same as `solver_solve_with_progress`, but `user_data` is passed to
+ * both `f` and `progress` as is.
+ */
+uint32_t solver_solve_with_progress_and_data(SolverHandle solver,
+                                             SolveCallbackWithData f,
+                                             ProgressCallbackWithData progress,
+                                             void *user_data);
+
+/**
+ * Same as `solver_solve`, but the solution is copied into `buf` of
+ * `buf_len` bytes, including the terminating nul, instead of being
+ * passed to a callback.
+ *
+ * The size of the solution, including the nul, is stored into `needed`
+ * unless it is null. If it exceeds `buf_len`, nothing is copied and
+ * `SOLVER_BUFFER_TOO_SMALL` is returned, `buf` can be null in this case.
+ * Solutions are cached, so calling this again with a bigger buffer
+ * doesn't solve the grid twice. Returns `SOLVER_NOT_FOUND` if there is
+ * no solution.
+ */
+uint32_t solver_solve_into(SolverHandle solver, char *buf, uintptr_t buf_len, uintptr_t *needed);
+
+/**
+ * Same as `solver_solve`, but the solution is stored into `solution`
+ * instead of being passed to a callback. The caller owns the string and
+ * must free it with `solver_string_free`. Returns `SOLVER_NOT_FOUND` if
+ * there is no solution.
+ */
+uint32_t solver_solve_owned(SolverHandle solver, char **solution);
+
+/**
+ * Free a string returned by the library, e.g. by `solver_solve_owned`.
+ * Does nothing if `string` is null.
+ */
+uint32_t solver_string_free(char *string);
+
+/**
+ * Destroy `solver`, invalidating its handle.
+ */
+uint32_t solver_destroy(SolverHandle solver);
+
+/**
+ * Create an executor passing the encoded responses to `recv`. Returns
+ * null on failure, see `solver_last_error_message`.
+ */
+void *capnp_init(RecvCallback recv);
+
+/**
+ * Same as `capnp_init`, but requests are processed by a pool of `workers` threads.
+ *
+ * Note that `recv` might be called from different threads, although
+ * never concurrently.
+ */
+void *capnp_init_with_workers(RecvCallback recv, uintptr_t workers);
+
+/**
+ * Same as `capnp_init`, but the executor is configured with `options`.
+ *
+ * Requests rejected due to overflow are responded with an error, on
+ * the thread that sent them, so `capnp_send` must not be called from `recv`
+ * if `max_pending` is set. Returns null if `options` are invalid.
+ */
+void *capnp_init_with_options(RecvCallback recv, ExecutorOptions options);
+
+/**
+ * Same as `capnp_init_with_options`, but `user_data` is passed to `recv`
+ * as is. `user_data` must stay valid until `capnp_destroy` returns.
+ */
+void *capnp_init_with_data(RecvCallbackWithData recv, void *user_data, ExecutorOptions options);
+
+/**
+ * Send the request `msg` to the executor.
+ *
+ * If `msg` is aligned to 8 bytes, it is read in place, otherwise it is
+ * copied first. Bytes past the end of the message, e.g. padding, are
+ * ignored. `msg` can be reused as soon as this returns.
+ *
+ * Requests that can't be decoded are responded with an error rather than
+ * failing the call.
+ */
+uint32_t capnp_send(void *executor, const uint8_t *msg, uintptr_t msg_len);
+
+/**
+ * Shut down and free the executor created by `capnp_init`.
+ *
+ * Unless `abort` is set, all requests sent so far are processed first.
+ * `recv` is never called after this returns. Must not be called from `recv`.
+ */
+uint32_t capnp_destroy(void *executor, bool abort);
+
+/**
+ * Deploy and run http server instance.
+ *
+ * Be aware! This will block the calling thread. Returns only if the
+ * server has failed to launch, with the status of the failure.
+ */
+uint32_t http_deploy(void);
+
+/**
+ * Same as `http_deploy`, but requests are processed by a pool of `workers` threads.
+ *
+ * Be aware! This will block the calling thread.
+ */
+uint32_t http_deploy_with_workers(uintptr_t workers);
+
+/**
+ * Same as `http_deploy`, but the executor is configured with `options`.
+ * Requests rejected due to overflow are responded with 503.
+ *
+ * Be aware! This will block the calling thread.
+ */
+uint32_t http_deploy_with_options(ExecutorOptions options);
+
+#if defined(TURBOSOLVER_UNIX)
+/**
+ * Serve `rpc.capnp` on a unix socket bound at `path`. Zero means unlimited
+ * for `max_bytes` and `max_solvers`, which are shared by all connections.
+ *
+ * Be aware! This will block the calling thread. Returns only if the
+ * socket couldn't be bound or accepting connections has failed.
+ */
+uint32_t rpc_serve_unix(const char *path, uintptr_t max_bytes, uintptr_t max_solvers);
+#endif
+
+#if defined(TURBOSOLVER_UNIX)
+/**
+ * Serve `rpc.capnp` on a background thread to a connected socket, which
+ * is stored to `fd`. The caller owns the socket, closing it stops the thread.
+ */
+uint32_t rpc_serve_pair(uintptr_t max_bytes, uintptr_t max_solvers, int *fd);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* TURBOSOLVER_H */
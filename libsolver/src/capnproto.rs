@@ -1,20 +1,20 @@
-
-use std::io::BufReader;
 use std::os::raw::c_void;
-use capnp::serialize;
-use capnp::message::ReaderOptions;
-use executor::{Executor, Req, ReqKind, Resp, RespKind};
+use std::ptr;
+use capnp::Word;
+use capnp::serialize::{self, OwnedSegments, SliceSegments};
+use capnp::message::{self, Allocator, Builder, HeapAllocator, ReaderOptions, ReaderSegments};
+use executor::{Config, Executor, ExecutorCallback, ExecutorOptions, Priority, Req,
+               ReqKind, Resp, RespKind, Shutdown, SOLVER_OVERFLOW_BLOCK};
+use context::{self, from_unix_millis, unix_millis, Event, EventKind, Limits, SolverInfo, Usage};
+use solver::{Assignment, Progress};
 use error::*;
+use ffi::{catch, non_null, status};
 use api_capnp;
 
-impl Req {
-    fn from_bytes(bytes: &[u8]) -> Result<Req> {
+impl ReqKind {
+    fn from_reader(req_root: api_capnp::req::Reader) -> Result<ReqKind> {
         use api_capnp::req::Which::*;
 
-        let mut reader = BufReader::new(&bytes[..]);
-        let msg = serialize::read_message(&mut reader, ReaderOptions::new())?;
-        let req_root = msg.get_root::<api_capnp::req::Reader>()?;
-
         let kind = match req_root.which() {
             Ok(CreateSolverReq(req)) => {
                 let grid = req?.get_grid()?.to_string();
@@ -28,72 +28,784 @@ impl Req {
                 let id = req?.get_id() as usize;
                 ReqKind::Destroy { id }
             }
-            _ => panic!("unsupported variant. Is schema up to date?"),
+            Ok(ListSolversReq(())) => ReqKind::ListSolvers,
+            Ok(DescribeSolverReq(req)) => {
+                let id = req?.get_id() as usize;
+                ReqKind::DescribeSolver { id }
+            }
+            Ok(CloneSolverReq(req)) => {
+                let id = req?.get_id() as usize;
+                ReqKind::CloneSolver { id }
+            }
+            Ok(SolveAssumingReq(req)) => {
+                let req = req?;
+                let id = req.get_id() as usize;
+                let assumptions_reader = req.get_assumptions()?;
+                let assumptions = (0..assumptions_reader.len())
+                    .map(|i| {
+                        let assumption = assumptions_reader.get(i);
+                        Assignment {
+                            row: assumption.get_row(),
+                            col: assumption.get_col(),
+                            digit: assumption.get_digit(),
+                        }
+                    })
+                    .collect();
+                ReqKind::SolveAssuming { id, assumptions }
+            }
+            Ok(UsageReq(())) => ReqKind::GetUsage,
+            Ok(SubscribeEventsReq(())) => ReqKind::SubscribeEvents,
+            Ok(BatchReq(req)) => {
+                let req = req?;
+                let reqs = req.get_reqs()?;
+                let kinds = (0..reqs.len())
+                    .map(|i| ReqKind::from_reader(reqs.get(i)))
+                    .collect::<Result<Vec<_>>>()?;
+                ReqKind::Batch {
+                    kinds,
+                    stop_on_error: req.get_stop_on_error(),
+                }
+            }
+            Ok(HandshakeReq(req)) => {
+                let req = req?;
+                let features_reader = req.get_features()?;
+                let features = (0..features_reader.len())
+                    .map(|i| features_reader.get(i).map(|feature| feature.to_string()))
+                    .collect::<::std::result::Result<Vec<_>, _>>()?;
+                ReqKind::Handshake {
+                    version: req.get_protocol_version(),
+                    features,
+                }
+            }
+            // Request from a newer client, responded with `badMessage`.
+            Err(not_in_schema) => bail!(ErrorKind::Capnp(not_in_schema.into())),
         };
+        Ok(kind)
+    }
+}
+
+/// Segments of a message read from a caller-owned buffer.
+enum Segments<'a> {
+    /// Read in place, without copying.
+    InPlace(SliceSegments<'a>),
+    Copied(OwnedSegments),
+}
+
+impl<'a> ReaderSegments for Segments<'a> {
+    fn get_segment<'b>(&'b self, id: u32) -> Option<&'b [Word]> {
+        match *self {
+            Segments::InPlace(ref segments) => segments.get_segment(id),
+            Segments::Copied(ref segments) => segments.get_segment(id),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match *self {
+            Segments::InPlace(ref segments) => segments.len(),
+            Segments::Copied(ref segments) => segments.len(),
+        }
+    }
+}
 
+/// Length in bytes of the message at the start of `bytes`, according to
+/// its segment table, or `None` if the table itself is truncated.
+fn message_len(bytes: &[u8]) -> Option<usize> {
+    fn read_u32(bytes: &[u8], index: usize) -> Option<usize> {
+        let bytes = bytes.get(4 * index..4 * index + 4)?;
+        Some(bytes.iter().rev().fold(0, |value, &byte| value << 8 | byte as usize))
+    }
+
+    let segment_count = read_u32(bytes, 0)?.checked_add(1)?;
+    // The table is padded to a word.
+    let table_words = segment_count / 2 + 1;
+    let mut words = table_words;
+    for i in 0..segment_count {
+        words = words.checked_add(read_u32(bytes, 1 + i)?)?;
+    }
+    words.checked_mul(8)
+}
+
+/// Read a message from `bytes`. It is read in place if `bytes` are
+/// aligned to a word, otherwise it has to be copied first.
+///
+/// Anything past the end of the message, e.g. padding added by the host,
+/// is ignored.
+fn read_message(bytes: &[u8]) -> Result<message::Reader<Segments>> {
+    let bytes = match message_len(bytes) {
+        Some(len) if len <= bytes.len() => &bytes[..len],
+        _ => bytes,
+    };
+    let aligned = bytes.as_ptr() as usize % 8 == 0 && bytes.len() % 8 == 0;
+    let segments = if aligned {
+        let words = Word::bytes_to_words(bytes);
+        let msg = serialize::read_message_from_words(words, ReaderOptions::new())?;
+        Segments::InPlace(msg.into_segments())
+    } else {
+        let mut reader = bytes;
+        let msg = serialize::read_message(&mut reader, ReaderOptions::new())?;
+        Segments::Copied(msg.into_segments())
+    };
+    Ok(message::Reader::new(segments, ReaderOptions::new()))
+}
+
+impl Req {
+    /// Decode a request from `bytes`. On failure the error is returned
+    /// along with the id of the request, if it could be read.
+    fn from_bytes(bytes: &[u8]) -> ::std::result::Result<Req, (Option<usize>, Error)> {
+        let msg = read_message(bytes).map_err(|e| (None, e))?;
+        let req_root = msg.get_root::<api_capnp::req::Reader>()
+            .map_err(|e| (None, e.into()))?;
+        Req::from_reader(req_root).map_err(|e| (Some(req_root.get_id() as usize), e))
+    }
+
+    pub(crate) fn from_reader(req_root: api_capnp::req::Reader) -> Result<Req> {
+        let kind = ReqKind::from_reader(req_root)?;
         let id = req_root.get_id() as usize;
+        // Priorities unknown to this version are treated as normal.
+        let priority = match req_root.get_priority() {
+            Ok(api_capnp::Priority::Low) => Priority::Low,
+            Ok(api_capnp::Priority::High) => Priority::High,
+            Ok(api_capnp::Priority::Normal) | Err(_) => Priority::Normal,
+        };
+
+        Ok(Req {
+            id,
+            kind,
+            priority,
+            report_progress: req_root.get_report_progress(),
+        })
+    }
+}
 
-        Ok(Req { id, kind })
+/// Fill `req_builder` with `req`, inverse of `Req::from_reader`.
+pub(crate) fn build_req(req: &Req, mut req_builder: api_capnp::req::Builder) {
+    req_builder.set_id(req.id as u32);
+    req_builder.set_priority(match req.priority {
+        Priority::Low => api_capnp::Priority::Low,
+        Priority::Normal => api_capnp::Priority::Normal,
+        Priority::High => api_capnp::Priority::High,
+    });
+    req_builder.set_report_progress(req.report_progress);
+    build_req_kind(&req.kind, req_builder);
+}
+
+fn build_req_kind(kind: &ReqKind, mut req_builder: api_capnp::req::Builder) {
+    match *kind {
+        ReqKind::CreateSolver { ref grid } => {
+            req_builder.init_create_solver_req().set_grid(grid);
+        }
+        ReqKind::Solve { id } => {
+            req_builder.init_solve_req().set_id(id as u32);
+        }
+        ReqKind::Destroy { id } => {
+            req_builder.init_destroy_req().set_id(id as u32);
+        }
+        ReqKind::ListSolvers => {
+            req_builder.set_list_solvers_req(());
+        }
+        ReqKind::DescribeSolver { id } => {
+            req_builder.init_describe_solver_req().set_id(id as u32);
+        }
+        ReqKind::CloneSolver { id } => {
+            req_builder.init_clone_solver_req().set_id(id as u32);
+        }
+        ReqKind::SolveAssuming {
+            id,
+            ref assumptions,
+        } => {
+            let mut req = req_builder.init_solve_assuming_req();
+            req.set_id(id as u32);
+            let mut assumptions_builder = req.init_assumptions(assumptions.len() as u32);
+            for (i, assumption) in assumptions.iter().enumerate() {
+                let mut assumption_builder = assumptions_builder.borrow().get(i as u32);
+                assumption_builder.set_row(assumption.row);
+                assumption_builder.set_col(assumption.col);
+                assumption_builder.set_digit(assumption.digit);
+            }
+        }
+        ReqKind::GetUsage => {
+            req_builder.set_usage_req(());
+        }
+        ReqKind::SubscribeEvents => {
+            req_builder.set_subscribe_events_req(());
+        }
+        ReqKind::Handshake {
+            version,
+            ref features,
+        } => {
+            let mut req = req_builder.init_handshake_req();
+            req.set_protocol_version(version);
+            let mut features_builder = req.init_features(features.len() as u32);
+            for (i, feature) in features.iter().enumerate() {
+                features_builder.set(i as u32, feature);
+            }
+        }
+        ReqKind::Batch {
+            ref kinds,
+            stop_on_error,
+        } => {
+            let mut req = req_builder.init_batch_req();
+            req.set_stop_on_error(stop_on_error);
+            let mut reqs = req.init_reqs(kinds.len() as u32);
+            for (i, kind) in kinds.iter().enumerate() {
+                build_req_kind(kind, reqs.borrow().get(i as u32));
+            }
+        }
+    }
+}
+
+/// Fill `resp_builder` with the response `kind` to the request `id`.
+pub(crate) fn build_resp(id: usize, kind: &Result<RespKind>, mut resp_builder: api_capnp::resp::Builder) {
+    resp_builder.set_id(id as u32);
+
+    match *kind {
+        Ok(ref kind) => {
+            let mut ok_resp = resp_builder.borrow().init_ok();
+            match *kind {
+                RespKind::SolverCreated { id } => {
+                    let mut resp = ok_resp.borrow().init_create_solver_resp();
+                    resp.set_id(id as u32);
+                }
+                RespKind::SolverResult { ref solution } => {
+                    let mut resp = ok_resp.borrow().init_solve_resp();
+                    resp.set_solution(solution);
+                }
+                RespKind::Destroyed => {
+                    ok_resp.borrow().set_destroy_resp(());
+                }
+                RespKind::SolverList { ref ids } => {
+                    let resp = ok_resp.borrow().init_list_solvers_resp();
+                    let mut ids_builder = resp.init_ids(ids.len() as u32);
+                    for (i, id) in ids.iter().enumerate() {
+                        ids_builder.set(i as u32, *id as u32);
+                    }
+                }
+                RespKind::SolverInfo { ref info } => {
+                    let mut resp = ok_resp.borrow().init_describe_solver_resp();
+                    resp.set_id(info.id as u32);
+                    resp.set_grid(&info.grid);
+                    resp.set_current_grid(&info.current_grid);
+                    resp.set_created_at(unix_millis(info.created));
+                    resp.set_last_used_at(unix_millis(info.last_used));
+                    resp.set_solved(info.solved);
+                }
+                RespKind::SolverCloned { id } => {
+                    let mut resp = ok_resp.borrow().init_clone_solver_resp();
+                    resp.set_id(id as u32);
+                }
+                RespKind::Usage { usage, limits } => {
+                    let mut resp = ok_resp.borrow().init_usage_resp();
+                    resp.set_bytes(usage.bytes as u64);
+                    resp.set_solvers(usage.solvers as u32);
+                    resp.set_max_bytes(limits.max_bytes.unwrap_or(0) as u64);
+                    resp.set_max_solvers(limits.max_solvers.unwrap_or(0) as u32);
+                }
+                RespKind::EventsSubscribed => {
+                    ok_resp.borrow().set_subscribe_events_resp(());
+                }
+                RespKind::Batch { ref results } => {
+                    let resp = ok_resp.borrow().init_batch_resp();
+                    let mut resps = resp.init_resps(results.len() as u32);
+                    for (i, result) in results.iter().enumerate() {
+                        build_resp(i, result, resps.borrow().get(i as u32));
+                    }
+                }
+                RespKind::Handshake {
+                    version,
+                    ref features,
+                } => {
+                    let mut resp = ok_resp.borrow().init_handshake_resp();
+                    resp.set_protocol_version(version);
+                    resp.set_error_codes_version(ERROR_CODES_VERSION);
+                    let mut features_builder = resp.init_features(features.len() as u32);
+                    for (i, feature) in features.iter().enumerate() {
+                        features_builder.set(i as u32, feature);
+                    }
+                }
+            }
+        }
+        Err(ref e) => {
+            resp_builder.set_err(error_code(e) as u32);
+            let mut detail = resp_builder.init_err_detail();
+            detail.set_message(&e.to_string());
+            if let ErrorKind::Context(context::ErrorKind::NotAvailable(id)) = *e.kind() {
+                detail.set_solver_id(id as u32);
+            }
+        }
+    }
+}
+
+/// Version of the mapping of errors onto `api_capnp::ErrorCode`, see
+/// `errorCodesVersion` in the schema.
+pub const ERROR_CODES_VERSION: u32 = 1;
+
+/// Code of `e` on the wire. Codes must stay stable across releases.
+pub(crate) fn error_code(e: &Error) -> api_capnp::ErrorCode {
+    use api_capnp::ErrorCode;
+
+    match *e.kind() {
+        ErrorKind::Context(ref kind) => match *kind {
+            context::ErrorKind::BadGrid => ErrorCode::BadGrid,
+            context::ErrorKind::NotAvailable(_) => ErrorCode::NotAvailable,
+            context::ErrorKind::SolutionNotFound => ErrorCode::SolutionNotFound,
+            context::ErrorKind::BadAssignment => ErrorCode::BadAssignment,
+            context::ErrorKind::QuotaExceeded => ErrorCode::QuotaExceeded,
+            _ => ErrorCode::Internal,
+        },
+        ErrorKind::Parse(_) => ErrorCode::BadGrid,
+        ErrorKind::Overloaded => ErrorCode::Overloaded,
+        ErrorKind::Utf8(_) | ErrorKind::Nul(_) | ErrorKind::Capnp(_) => ErrorCode::BadMessage,
+        _ => ErrorCode::Internal,
+    }
+}
+
+/// Inverse of `error_code`. Errors without a kind of their own are
+/// restored as `ErrorKind::Msg` with the original message.
+fn error_from_code(code: u32, detail: api_capnp::error_detail::Reader) -> Result<Error> {
+    use api_capnp::ErrorCode;
+    use capnp::traits::FromU16;
+
+    let message = detail.get_message()?.to_string();
+    let code = if code <= u16::max_value() as u32 {
+        ErrorCode::from_u16(code as u16).ok()
+    } else {
+        None
+    };
+    let kind = match code {
+        Some(ErrorCode::BadGrid) => context::ErrorKind::BadGrid.into(),
+        Some(ErrorCode::NotAvailable) => {
+            context::ErrorKind::NotAvailable(detail.get_solver_id() as usize).into()
+        }
+        Some(ErrorCode::SolutionNotFound) => context::ErrorKind::SolutionNotFound.into(),
+        Some(ErrorCode::BadAssignment) => context::ErrorKind::BadAssignment.into(),
+        Some(ErrorCode::QuotaExceeded) => context::ErrorKind::QuotaExceeded.into(),
+        Some(ErrorCode::Overloaded) => ErrorKind::Overloaded,
+        Some(ErrorCode::Internal) | Some(ErrorCode::BadMessage) | None => ErrorKind::Msg(message),
+    };
+    Ok(kind.into())
+}
+
+impl RespKind {
+    fn from_reader(ok_resp: api_capnp::ok_resp::Reader) -> Result<RespKind> {
+        use api_capnp::ok_resp::Which::*;
+
+        let kind = match ok_resp.which() {
+            Ok(CreateSolverResp(resp)) => RespKind::SolverCreated {
+                id: resp?.get_id() as usize,
+            },
+            Ok(SolveResp(resp)) => RespKind::SolverResult {
+                solution: resp?.get_solution()?.to_string(),
+            },
+            Ok(DestroyResp(())) => RespKind::Destroyed,
+            Ok(ListSolversResp(resp)) => {
+                let ids_reader = resp?.get_ids()?;
+                let ids = (0..ids_reader.len())
+                    .map(|i| ids_reader.get(i) as usize)
+                    .collect();
+                RespKind::SolverList { ids }
+            }
+            Ok(DescribeSolverResp(resp)) => {
+                let resp = resp?;
+                let info = SolverInfo {
+                    id: resp.get_id() as usize,
+                    grid: resp.get_grid()?.to_string(),
+                    current_grid: resp.get_current_grid()?.to_string(),
+                    created: from_unix_millis(resp.get_created_at()),
+                    last_used: from_unix_millis(resp.get_last_used_at()),
+                    solved: resp.get_solved(),
+                };
+                RespKind::SolverInfo { info }
+            }
+            Ok(CloneSolverResp(resp)) => RespKind::SolverCloned {
+                id: resp?.get_id() as usize,
+            },
+            Ok(UsageResp(resp)) => {
+                let resp = resp?;
+                let non_zero = |limit| if limit == 0 { None } else { Some(limit as usize) };
+                RespKind::Usage {
+                    usage: Usage {
+                        bytes: resp.get_bytes() as usize,
+                        solvers: resp.get_solvers() as usize,
+                    },
+                    limits: Limits {
+                        max_bytes: non_zero(resp.get_max_bytes()),
+                        max_solvers: non_zero(resp.get_max_solvers() as u64),
+                    },
+                }
+            }
+            Ok(SubscribeEventsResp(())) => RespKind::EventsSubscribed,
+            Ok(BatchResp(resp)) => {
+                let resps = resp?.get_resps()?;
+                let results = (0..resps.len())
+                    .map(|i| Resp::from_reader(resps.get(i)).map(|resp| resp.kind))
+                    .collect::<Result<Vec<_>>>()?;
+                RespKind::Batch { results }
+            }
+            Ok(HandshakeResp(resp)) => {
+                let resp = resp?;
+                let features_reader = resp.get_features()?;
+                let features = (0..features_reader.len())
+                    .map(|i| features_reader.get(i).map(|feature| feature.to_string()))
+                    .collect::<::std::result::Result<Vec<_>, _>>()?;
+                RespKind::Handshake {
+                    version: resp.get_protocol_version(),
+                    features,
+                }
+            }
+            Err(::capnp::NotInSchema(variant)) => bail!(ErrorKind::Internal(format!(
+                "unknown response variant {}",
+                variant
+            ))),
+        };
+        Ok(kind)
     }
 }
 
 impl Resp {
-    fn into_bytes(self) -> Vec<u8> {
-        let mut message = ::capnp::message::Builder::new_default();
+    /// Decode the response to a request, inverse of `build_resp`.
+    pub(crate) fn from_reader(resp_root: api_capnp::resp::Reader) -> Result<Resp> {
+        let kind = match resp_root.which() {
+            Ok(api_capnp::resp::Ok(ok_resp)) => Ok(RespKind::from_reader(ok_resp?)?),
+            Ok(api_capnp::resp::Err(code)) => {
+                Err(error_from_code(code, resp_root.get_err_detail()?)?)
+            }
+            _ => bail!(ErrorKind::Internal("not a response to a request".to_string())),
+        };
+        Ok(Resp {
+            id: resp_root.get_id() as usize,
+            kind,
+        })
+    }
+}
+
+fn build_event(event: Event, resp_builder: api_capnp::resp::Builder) {
+    let mut event_builder = resp_builder.init_event();
+    event_builder.set_solver_id(event.id as u32);
+    event_builder.set_kind(match event.kind {
+        EventKind::Created => api_capnp::EventKind::Created,
+        EventKind::Solved => api_capnp::EventKind::Solved,
+        EventKind::Destroyed => api_capnp::EventKind::Destroyed,
+    });
+}
+
+/// Number of words of the first segment built in a `MessageBuffer`.
+const FIRST_SEGMENT_WORDS: usize = 1024;
+
+/// Reusable buffer the outgoing messages are built in.
+///
+/// The first segment of a message is built in place, right after the
+/// word reserved for the segment table, so single-segment messages are
+/// passed on without copying or allocating. Messages that don't fit are
+/// serialized into a new vector.
+struct MessageBuffer {
+    words: Vec<Word>,
+}
+
+/// Allocator of the first segment in a `MessageBuffer`.
+struct InPlaceAllocator<'a> {
+    first_segment: Option<&'a mut [Word]>,
+    heap: HeapAllocator,
+}
+
+unsafe impl<'a> Allocator for InPlaceAllocator<'a> {
+    fn allocate_segment(&mut self, minimum_size: u32) -> (*mut Word, u32) {
+        // The buffer is zeroed after every message, see `ZeroOnDrop`.
+        if let Some(words) = self.first_segment.take() {
+            if words.len() >= minimum_size as usize {
+                return (words.as_mut_ptr(), words.len() as u32);
+            }
+        }
+        self.heap.allocate_segment(minimum_size)
+    }
+}
+
+/// Zeroes the first `len` words of the first segment of `buffer` when
+/// dropped, so the buffer is clean for the next message even if building
+/// or sending this one panics.
+struct ZeroOnDrop<'a> {
+    buffer: &'a mut MessageBuffer,
+    len: usize,
+}
+
+impl<'a> Drop for ZeroOnDrop<'a> {
+    fn drop(&mut self) {
+        for byte in Word::words_to_bytes_mut(&mut self.buffer.words[1..1 + self.len]) {
+            *byte = 0;
+        }
+    }
+}
+
+impl MessageBuffer {
+    fn new() -> MessageBuffer {
+        MessageBuffer {
+            words: Word::allocate_zeroed_vec(1 + FIRST_SEGMENT_WORDS),
+        }
+    }
+
+    /// Build a `Resp` message with `build` and pass it serialized to `send`.
+    fn send<B, S>(&mut self, build: B, send: &mut S)
+    where
+        B: FnOnce(api_capnp::resp::Builder),
+        S: FnMut(&mut [u8]),
+    {
+        // Until it is known how much of the first segment is used,
+        // all of it is zeroed.
+        let mut guard = ZeroOnDrop {
+            buffer: self,
+            len: FIRST_SEGMENT_WORDS,
+        };
+        let in_place_len;
+        let mut copied = None;
         {
-            let mut resp_builder = message.init_root::<api_capnp::resp::Builder>();
-            resp_builder.set_id(self.id as u32);
-
-            match self.kind {
-                Ok(kind) => {
-                    let mut ok_resp = resp_builder.borrow().init_ok();
-                    match kind {
-                        RespKind::SolverCreated { id } => {
-                            let mut resp = ok_resp.borrow().init_create_solver_resp();
-                            resp.set_id(id as u32);
-                        }
-                        RespKind::SolverResult { ref solution } => {
-                            let mut resp = ok_resp.borrow().init_solve_resp();
-                            resp.set_solution(solution);
-                        }
-                        RespKind::Destroyed => {
-                            ok_resp.borrow().set_destroy_resp(());
-                        }
+            let first_segment = &mut guard.buffer.words[1..];
+            let first_segment_ptr = first_segment.as_ptr();
+            let mut message = Builder::new(InPlaceAllocator {
+                first_segment: Some(first_segment),
+                heap: HeapAllocator::new(),
+            });
+            build(message.init_root::<api_capnp::resp::Builder>());
+
+            let segments = message.get_segments_for_output();
+            in_place_len = if segments[0].as_ptr() == first_segment_ptr {
+                segments[0].len()
+            } else {
+                0
+            };
+            if segments.len() > 1 || in_place_len == 0 {
+                let mut bytes = Vec::new();
+                serialize::write_message(&mut bytes, &message).expect("write_message should succeed");
+                copied = Some(bytes);
+            }
+        }
+        guard.len = in_place_len;
+
+        match copied {
+            Some(mut bytes) => send(&mut bytes),
+            None => {
+                let words = &mut guard.buffer.words;
+                // Segment table of a single segment: zero (the number of
+                // segments minus one), followed by the segment length.
+                {
+                    let table = Word::words_to_bytes_mut(&mut words[..1]);
+                    for (i, byte) in table.iter_mut().enumerate() {
+                        *byte = if i < 4 { 0 } else { (in_place_len >> (8 * (i - 4))) as u8 };
                     }
                 }
-                Err(_e) => {
-                    // TODO: turn _e into errno.
-                    resp_builder.set_err(1);
-                }
+                send(Word::words_to_bytes_mut(&mut words[..1 + in_place_len]));
             }
         }
+    }
+}
 
-        let mut bytes = Vec::new();
-        serialize::write_message(&mut bytes, &message).expect("write_message should succeed");
-        bytes
+/// Executor callback that serializes messages and passes them to `send`.
+/// The bytes are only valid until `send` returns.
+///
+/// Events are forwarded only after the client subscribed to them, since
+/// older clients don't know how to handle them.
+struct CapnpCallback<F> {
+    send: F,
+    subscribed: bool,
+    // Calls to the callback are never concurrent, so a single buffer is enough.
+    buffer: MessageBuffer,
+}
+
+impl<F: FnMut(&mut [u8]) + Send> CapnpCallback<F> {
+    fn new(send: F) -> CapnpCallback<F> {
+        CapnpCallback {
+            send,
+            subscribed: false,
+            buffer: MessageBuffer::new(),
+        }
+    }
+}
+
+/// Whether `resp_kind` confirms a subscription to events, possibly as
+/// a part of a batch.
+fn subscribes(resp_kind: &RespKind) -> bool {
+    match *resp_kind {
+        RespKind::EventsSubscribed => true,
+        RespKind::Batch { ref results } => results
+            .iter()
+            .any(|result| result.as_ref().map_or(false, subscribes)),
+        _ => false,
+    }
+}
+
+impl<F: FnMut(&mut [u8]) + Send> ExecutorCallback for CapnpCallback<F> {
+    fn call(&mut self, resp: Resp) {
+        if resp.kind.as_ref().map_or(false, subscribes) {
+            self.subscribed = true;
+        }
+        self.buffer.send(
+            |resp_builder| build_resp(resp.id, &resp.kind, resp_builder),
+            &mut self.send,
+        );
+    }
+
+    fn event(&mut self, event: Event) {
+        if self.subscribed {
+            self.buffer
+                .send(|resp_builder| build_event(event, resp_builder), &mut self.send);
+        }
+    }
+
+    fn progress(&mut self, id: usize, progress: Progress) {
+        let build = |mut resp_builder: api_capnp::resp::Builder| {
+            resp_builder.set_id(id as u32);
+            let mut progress_builder = resp_builder.init_progress();
+            progress_builder.set_done(progress.done);
+            progress_builder.set_total(progress.total);
+        };
+        self.buffer.send(build, &mut self.send);
     }
+
+    fn worker_restarted(&mut self, message: &str) {
+        if !self.subscribed {
+            return;
+        }
+
+        self.buffer.send(
+            |mut resp_builder| resp_builder.set_worker_restarted(message),
+            &mut self.send,
+        );
+    }
+}
+
+/// Callback receiving encoded responses and events. The message is valid
+/// only during the call.
+pub type RecvCallback = extern "C" fn(msg: *const u8, msg_len: usize);
+
+/// Same as `RecvCallback`, with the `user_data` passed to `capnp_init_with_data`.
+pub type RecvCallbackWithData =
+    extern "C" fn(user_data: *mut c_void, msg: *const u8, msg_len: usize);
+
+/// Pointer passed by the caller, opaque to the library.
+struct UserData(*mut c_void);
+
+// It is up to the caller to make `user_data` usable from the worker threads.
+unsafe impl Send for UserData {}
+
+/// Create an executor passing the encoded responses to `recv`. Returns
+/// null on failure, see `solver_last_error_message`.
+#[no_mangle]
+pub extern "C" fn capnp_init(recv: RecvCallback) -> *mut c_void {
+    capnp_init_with_workers(recv, 1)
 }
 
+/// Same as `capnp_init`, but requests are processed by a pool of `workers` threads.
+///
+/// Note that `recv` might be called from different threads, although
+/// never concurrently.
 #[no_mangle]
-pub extern "C" fn capnp_init(recv: extern "C" fn(*const u8, usize)) -> *mut c_void {
-    let f = move |resp: Resp| {
-        let bytes = resp.into_bytes();
-        recv(bytes.as_ptr(), bytes.len())
+pub extern "C" fn capnp_init_with_workers(recv: RecvCallback, workers: usize) -> *mut c_void {
+    let options = ExecutorOptions {
+        workers,
+        max_pending: 0,
+        overflow: SOLVER_OVERFLOW_BLOCK,
+        max_bytes: 0,
+        max_solvers: 0,
+        journal_path: ptr::null(),
     };
-    let dispatcher = Box::new(Executor::new(f));
-    Box::into_raw(dispatcher) as *mut c_void
+    capnp_init_with_options(recv, options)
 }
 
+/// Same as `capnp_init`, but the executor is configured with `options`.
+///
+/// Requests rejected due to overflow are responded with an error, on
+/// the thread that sent them, so `capnp_send` must not be called from `recv`
+/// if `max_pending` is set. Returns null if `options` are invalid.
 #[no_mangle]
-pub extern "C" fn capnp_send(this: *mut c_void, msg: *const u8, msg_len: usize) {
+pub extern "C" fn capnp_init_with_options(
+    recv: RecvCallback,
+    options: ExecutorOptions,
+) -> *mut c_void {
+    init(move |bytes: &mut [u8]| recv(bytes.as_ptr(), bytes.len()), options)
+}
+
+/// Same as `capnp_init_with_options`, but `user_data` is passed to `recv`
+/// as is. `user_data` must stay valid until `capnp_destroy` returns.
+#[no_mangle]
+pub extern "C" fn capnp_init_with_data(
+    recv: RecvCallbackWithData,
+    user_data: *mut c_void,
+    options: ExecutorOptions,
+) -> *mut c_void {
+    let user_data = UserData(user_data);
+    init(
+        move |bytes: &mut [u8]| recv(user_data.0, bytes.as_ptr(), bytes.len()),
+        options,
+    )
+}
+
+/// Shared part of the exported constructors, `options` are received from
+/// the host as is.
+fn init<F: FnMut(&mut [u8]) + Send + 'static>(send: F, options: ExecutorOptions) -> *mut c_void {
+    // The header requires `journal_path` to be null or a valid string
+    // for the duration of the call.
+    match catch(|| Config::from_options(options, unsafe { options.journal_path()? })) {
+        Ok(config) => init_with_config(send, config),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Same as `capnp_init_with_options`, but for Rust hosts, which can use
+/// everything `Config` offers, e.g. interceptors. Responses are passed to
+/// `send`. The executor is used with `capnp_send` and `capnp_destroy`.
+pub fn init_with_config<F: FnMut(&mut [u8]) + Send + 'static>(
+    send: F,
+    config: Config,
+) -> *mut c_void {
+    catch(|| {
+        let dispatcher = Box::new(Executor::with_config(config, CapnpCallback::new(send)));
+        Ok(Box::into_raw(dispatcher) as *mut c_void)
+    }).unwrap_or(ptr::null_mut())
+}
+
+/// Decode the request from `bytes` and send it to `executor`.
+///
+/// Requests that can't be decoded, e.g. of kinds unknown to this version,
+/// are responded with an error. The response has the id of the request,
+/// or 0 if even the id couldn't be read.
+fn send_bytes(executor: &Executor, bytes: &[u8]) {
+    match Req::from_bytes(bytes) {
+        Ok(req) => executor.send(req),
+        Err((id, e)) => executor.reject(id.unwrap_or(0), e),
+    }
+}
+
+/// Send the request `msg` to the executor.
+///
+/// If `msg` is aligned to 8 bytes, it is read in place, otherwise it is
+/// copied first. Bytes past the end of the message, e.g. padding, are
+/// ignored. `msg` can be reused as soon as this returns.
+///
+/// Requests that can't be decoded are responded with an error rather than
+/// failing the call.
+#[no_mangle]
+pub extern "C" fn capnp_send(executor: *mut c_void, msg: *const u8, msg_len: usize) -> u32 {
     use std::slice;
-    unsafe {
-        let this = this as *mut Executor;
-        let executor = this.as_mut().expect("`this` shouldn't be null");
+    status(catch(|| unsafe {
+        non_null(executor, "executor")?;
+        non_null(msg, "msg")?;
+        let executor = &*(executor as *mut Executor);
         let msg = slice::from_raw_parts(msg, msg_len);
-        let req = Req::from_bytes(msg).expect("wrong schema?");
-        executor.send(req);
-    }
+        send_bytes(executor, msg);
+        Ok(())
+    }))
+}
+
+/// Shut down and free the executor created by `capnp_init`.
+///
+/// Unless `abort` is set, all requests sent so far are processed first.
+/// `recv` is never called after this returns. Must not be called from `recv`.
+#[no_mangle]
+pub extern "C" fn capnp_destroy(executor: *mut c_void, abort: bool) -> u32 {
+    status(catch(|| {
+        non_null(executor, "executor")?;
+        let mut executor = unsafe { Box::from_raw(executor as *mut Executor) };
+        executor.shutdown(if abort { Shutdown::Abort } else { Shutdown::Drain });
+        Ok(())
+    }))
 }
 
 #[cfg(target_os = "android")]
@@ -102,11 +814,11 @@ pub mod jni {
     extern crate jni;
 
     use super::*;
-    use executor::ExecutorCallback;
+    use ffi::{last_error_message, SOLVER_OK};
 
     use self::jni::JNIEnv;
-    use self::jni::objects::{GlobalRef, JClass, JObject, JValue};
-    use self::jni::sys::jlong;
+    use self::jni::objects::{GlobalRef, JByteBuffer, JClass, JObject, JString, JValue};
+    use self::jni::sys::{jboolean, jint, jlong, JNI_FALSE};
     use self::jni::sys::JNIEnv as RawJNIEnv;
     use self::jni::sys::{JavaVM, jbyteArray};
 
@@ -118,12 +830,12 @@ pub mod jni {
         dispatcher_this: GlobalRef,
     }
 
-    impl ExecutorCallback for Context {
-        fn call(&mut self, r: Resp) {
-            let mut result_bytes = r.into_bytes();
-
+    impl Context {
+        /// Pass `result_bytes` to the dispatcher as a direct `ByteBuffer`,
+        /// which is valid only during the callback.
+        fn send(&mut self, result_bytes: &mut [u8]) {
             with_attached_thread(self.vm, |env| {
-                let byte_buf = env.new_direct_byte_buffer(&mut result_bytes).unwrap();
+                let byte_buf = env.new_direct_byte_buffer(result_bytes).unwrap();
                 let byte_buf_obj = JValue::Object(*byte_buf);
 
                 let dispatcher_this = self.dispatcher_this.as_obj();
@@ -170,30 +882,116 @@ pub mod jni {
         }
     }
 
+    /// Value of `result`, or `default` if it is a failure, in which case
+    /// `RuntimeException` with the last error is thrown when returning to Java.
+    pub(crate) fn throw_on_failure<T>(
+        env: &JNIEnv,
+        result: ::std::result::Result<T, u32>,
+        default: T,
+    ) -> T {
+        result.unwrap_or_else(|_| {
+            let _ = env.throw_new("java/lang/RuntimeException", last_error_message());
+            default
+        })
+    }
+
     #[no_mangle]
     pub extern "C" fn Java_me_pepyakin_turbosolver_capnp_Dispatcher_capnp_1init(
         env: JNIEnv,
         _: JClass,
         this: JObject,
     ) -> jlong {
-        // Pin `this` object, this will prevent `this` to be garbage collected.
-        let dispatcher_this = env.new_global_ref(this).unwrap();
+        init_with_config(env, this, Config::default())
+    }
+
+    #[no_mangle]
+    pub extern "C" fn Java_me_pepyakin_turbosolver_capnp_Dispatcher_capnp_1init_1with_1workers(
+        env: JNIEnv,
+        _: JClass,
+        this: JObject,
+        workers: jint,
+    ) -> jlong {
+        let config = Config {
+            workers: workers as usize,
+            ..Config::default()
+        };
+        init_with_config(env, this, config)
+    }
+
+    /// Same as `capnp_init_with_workers`, but the executor is configured as
+    /// with `ExecutorOptions`, zero means unlimited. `overflow` is one of
+    /// `SOLVER_OVERFLOW_*` codes. The journal is recorded into `journal_path`,
+    /// unless it is null.
+    #[no_mangle]
+    pub extern "C" fn Java_me_pepyakin_turbosolver_capnp_Dispatcher_capnp_1init_1with_1options(
+        env: JNIEnv,
+        _: JClass,
+        this: JObject,
+        workers: jint,
+        max_pending: jint,
+        overflow: jint,
+        max_bytes: jlong,
+        max_solvers: jint,
+        journal_path: JString,
+    ) -> jlong {
+        let config = catch(|| {
+            let journal_path = if journal_path.is_null() {
+                None
+            } else {
+                let path: String = env.get_string(journal_path).map_err(|e| e.to_string())?.into();
+                Some(path)
+            };
+            let options = ExecutorOptions {
+                workers: non_negative(workers as jlong, "workers")?,
+                max_pending: non_negative(max_pending as jlong, "maxPending")?,
+                overflow: non_negative(overflow as jlong, "overflow")? as u32,
+                max_bytes: non_negative(max_bytes, "maxBytes")?,
+                max_solvers: non_negative(max_solvers as jlong, "maxSolvers")?,
+                journal_path: ptr::null(),
+            };
+            Config::from_options(options, journal_path.as_ref().map(|path| path.as_str()))
+        });
+        match config {
+            Ok(config) => init_with_config(env, this, config),
+            Err(status) => throw_on_failure(&env, Err(status), 0),
+        }
+    }
+
+    /// Fail with `InvalidArgument` if `value` is negative.
+    fn non_negative(value: jlong, name: &str) -> Result<usize> {
+        if value < 0 {
+            bail!(ErrorKind::InvalidArgument(format!("`{}` is negative", name)));
+        }
+        Ok(value as usize)
+    }
+
+    /// Create the executor of `this` dispatcher configured with `config`.
+    ///
+    /// Used by the native methods of `Dispatcher`, and by Rust hosts that
+    /// register their own, e.g. to pass interceptors.
+    pub fn init_with_config(env: JNIEnv, this: JObject, config: Config) -> jlong {
+        let executor = catch(|| unsafe {
+            // Pin `this` object, this will prevent `this` to be garbage collected.
+            let dispatcher_this = env.new_global_ref(this).map_err(|e| e.to_string())?;
 
-        unsafe {
             // Extract JavaVM: it will be needed later in the callback.
             let raw_env = env.get_native_interface();
             let mut java_vm: *mut JavaVM = ptr::null_mut();
-            let get_java_vm = (**raw_env).GetJavaVM.unwrap();
+            let get_java_vm = (**raw_env).GetJavaVM.ok_or("JNIEnv doesn't have GetJavaVM")?;
             let result = get_java_vm(raw_env, &mut java_vm as *mut *mut _);
-            assert!(result == 0);
+            if result != 0 {
+                bail!("GetJavaVM has failed with {}", result);
+            }
 
-            let ctx = Context {
+            let mut ctx = Context {
                 vm: java_vm,
                 dispatcher_this,
             };
-            let executor = Box::new(Executor::new(ctx));
-            Box::into_raw(executor) as *mut c_void as jlong
-        }
+            let callback = CapnpCallback::new(move |bytes: &mut [u8]| ctx.send(bytes));
+            let executor = Box::new(Executor::with_config(config, callback));
+            Ok(Box::into_raw(executor) as *mut c_void as jlong)
+        });
+        throw_on_failure(&env, executor, 0)
     }
 
     #[no_mangle]
@@ -203,20 +1001,271 @@ pub mod jni {
         executor_ptr: jlong,
         data: jbyteArray,
     ) {
-        // TODO: Can we get away without copying here?
-        let data_vec = env.convert_byte_array(data).expect("couldn't copy `data`");
-        let req = Req::from_bytes(&data_vec).expect("wrong schema?");
+        let sent = catch(|| unsafe {
+            let executor = executor_ptr as *mut c_void as *mut Executor;
+            non_null(executor, "executor")?;
+            // Array elements can't be accessed in place, use `capnp_send_direct` to avoid the copy.
+            let data_vec = env.convert_byte_array(data).map_err(|e| e.to_string())?;
+            send_bytes(&*executor, &data_vec);
+            Ok(())
+        });
+        throw_on_failure(&env, sent, ())
+    }
 
-        unsafe {
+    /// Same as `capnp_send`, but the message is read in place from the first
+    /// `len` bytes of the direct buffer `data`. The buffer can be reused as
+    /// soon as this returns.
+    #[no_mangle]
+    pub extern "C" fn Java_me_pepyakin_turbosolver_capnp_Dispatcher_capnp_1send_1direct(
+        env: JNIEnv,
+        _: JClass,
+        executor_ptr: jlong,
+        data: JByteBuffer,
+        len: jint,
+    ) {
+        let sent = catch(|| unsafe {
             let executor = executor_ptr as *mut c_void as *mut Executor;
-            executor.as_mut().expect("`executor` is null").send(req);
-        }
+            non_null(executor, "executor")?;
+            let data = env.get_direct_buffer_address(data).map_err(|e| e.to_string())?;
+            let data = data.get(..len as usize).ok_or_else(|| {
+                ErrorKind::InvalidArgument(format!("`len` {} exceeds the buffer", len))
+            })?;
+            send_bytes(&*executor, data);
+            Ok(())
+        });
+        throw_on_failure(&env, sent, ())
+    }
+
+    #[no_mangle]
+    pub extern "C" fn Java_me_pepyakin_turbosolver_capnp_Dispatcher_capnp_1destroy(
+        env: JNIEnv,
+        _: JClass,
+        executor_ptr: jlong,
+        abort: jboolean,
+    ) {
+        let status = capnp_destroy(executor_ptr as *mut c_void, abort != JNI_FALSE);
+        let destroyed = if status == SOLVER_OK { Ok(()) } else { Err(status) };
+        throw_on_failure(&env, destroyed, ())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::BufReader;
+    use test::Bencher;
+
+    impl Resp {
+        fn into_bytes(self) -> Vec<u8> {
+            let mut bytes = Vec::new();
+            MessageBuffer::new().send(
+                |resp_builder| build_resp(self.id, &self.kind, resp_builder),
+                &mut |msg: &mut [u8]| bytes.extend_from_slice(msg),
+            );
+            bytes
+        }
+    }
+
+    /// Serialized `Solve` request, aligned to a word.
+    fn solve_req_words() -> Vec<Word> {
+        let mut message = ::capnp::message::Builder::new_default();
+        message
+            .init_root::<api_capnp::req::Builder>()
+            .init_solve_req()
+            .set_id(3);
+        serialize::write_message_to_words(&message)
+    }
+
+    /// Same as `solve_req_words`, but misaligned by one byte.
+    fn solve_req_misaligned() -> Vec<u8> {
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(Word::words_to_bytes(&solve_req_words()));
+        bytes
+    }
+
+    #[test]
+    fn test_read_in_place() {
+        let words = solve_req_words();
+        match read_message(Word::words_to_bytes(&words)).unwrap().into_segments() {
+            Segments::InPlace(segments) => {
+                assert_eq!(segments.get_segment(0).unwrap().as_ptr(), words[1..].as_ptr())
+            }
+            Segments::Copied(_) => panic!("aligned message should be read in place"),
+        }
+
+        let bytes = solve_req_misaligned();
+        match read_message(&bytes[1..]).unwrap().into_segments() {
+            Segments::Copied(_) => {}
+            Segments::InPlace(_) => panic!("misaligned message can't be read in place"),
+        }
+        match Req::from_bytes(&bytes[1..]).unwrap().kind {
+            ReqKind::Solve { id: 3 } => {}
+            _ => panic!("expected solve request"),
+        }
+    }
+
+    #[test]
+    fn test_read_with_trailing_padding() {
+        let expect_solve = |bytes: &[u8]| match Req::from_bytes(bytes).unwrap().kind {
+            ReqKind::Solve { id: 3 } => {}
+            _ => panic!("expected solve request"),
+        };
+
+        // Aligned, padded with a word.
+        let mut words = solve_req_words();
+        let len = words.len();
+        words.push(Word::allocate_zeroed_vec(1)[0]);
+        let bytes = Word::words_to_bytes(&words);
+        match read_message(bytes).unwrap().into_segments() {
+            Segments::InPlace(segments) => {
+                assert_eq!(segments.get_segment(0).unwrap().len(), len - 1)
+            }
+            Segments::Copied(_) => panic!("aligned message should be read in place"),
+        }
+        expect_solve(bytes);
+
+        // Aligned, padded with less than a word.
+        let mut words = solve_req_words();
+        words.push(Word::allocate_zeroed_vec(1)[0]);
+        let bytes = &Word::words_to_bytes(&words)[..8 * len + 3];
+        match read_message(bytes).unwrap().into_segments() {
+            Segments::InPlace(_) => {}
+            Segments::Copied(_) => panic!("aligned message should be read in place"),
+        }
+        expect_solve(bytes);
+
+        // Misaligned, padded.
+        let mut bytes = solve_req_misaligned();
+        bytes.extend_from_slice(&[0; 5]);
+        match read_message(&bytes[1..]).unwrap().into_segments() {
+            Segments::Copied(_) => {}
+            Segments::InPlace(_) => panic!("misaligned message can't be read in place"),
+        }
+        expect_solve(&bytes[1..]);
+
+        // Truncated messages are still rejected.
+        let words = solve_req_words();
+        assert!(read_message(&Word::words_to_bytes(&words)[..8 * (len - 1)]).is_err());
+    }
+
+    #[test]
+    fn test_message_buffer_zeroed_on_panic() {
+        use std::panic::{self, AssertUnwindSafe};
+
+        let mut buffer = MessageBuffer::new();
+        let resp = Ok(RespKind::SolverList { ids: vec![1, 2, 3] });
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            buffer.send(
+                |resp_builder| build_resp(0, &resp, resp_builder),
+                &mut |_: &mut [u8]| panic!("send failed"),
+            )
+        }));
+        assert!(result.is_err());
+        assert!(Word::words_to_bytes(&buffer.words[1..]).iter().all(|&byte| byte == 0));
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            buffer.send(
+                |resp_builder| {
+                    build_resp(0, &resp, resp_builder);
+                    panic!("build failed")
+                },
+                &mut |_: &mut [u8]| {},
+            )
+        }));
+        assert!(result.is_err());
+        assert!(Word::words_to_bytes(&buffer.words[1..]).iter().all(|&byte| byte == 0));
+    }
+
+    #[test]
+    fn test_message_buffer() {
+        let mut buffer = MessageBuffer::new();
+        let decode_ids = |bytes: &[u8]| {
+            let msg = serialize::read_message(&mut &bytes[..], ReaderOptions::new()).unwrap();
+            let resp_root = msg.get_root::<api_capnp::resp::Reader>().unwrap();
+            match RespKind::from_reader(match resp_root.which() {
+                Ok(api_capnp::resp::Ok(ok_resp)) => ok_resp.unwrap(),
+                _ => panic!("expected ok response"),
+            }) {
+                Ok(RespKind::SolverList { ids }) => ids,
+                _ => panic!("expected solver list"),
+            }
+        };
+        let send_ids = |buffer: &mut MessageBuffer, ids: Vec<usize>| {
+            let mut sent = None;
+            let resp = Ok(RespKind::SolverList { ids });
+            buffer.send(
+                |resp_builder| build_resp(0, &resp, resp_builder),
+                &mut |bytes: &mut [u8]| sent = Some((bytes.as_ptr(), decode_ids(bytes))),
+            );
+            sent.unwrap()
+        };
+
+        // Small message is passed right from the buffer.
+        let (ptr, ids) = send_ids(&mut buffer, vec![1, 2, 3]);
+        assert_eq!(ptr, Word::words_to_bytes(&buffer.words).as_ptr());
+        assert_eq!(ids, vec![1, 2, 3]);
+
+        // Message that doesn't fit is copied.
+        let many: Vec<usize> = (0..4 * FIRST_SEGMENT_WORDS).collect();
+        let (ptr, ids) = send_ids(&mut buffer, many.clone());
+        assert_ne!(ptr, Word::words_to_bytes(&buffer.words).as_ptr());
+        assert_eq!(ids, many);
+
+        // The buffer is still usable, and clean.
+        let (_, ids) = send_ids(&mut buffer, vec![]);
+        assert!(ids.is_empty());
+        assert!(Word::words_to_bytes(&buffer.words[1..]).iter().all(|&byte| byte == 0));
+    }
+
+    /// No copies of the request.
+    #[bench]
+    fn bench_decode_in_place(b: &mut Bencher) {
+        let words = solve_req_words();
+        let bytes = Word::words_to_bytes(&words);
+        b.bytes = bytes.len() as u64;
+        b.iter(|| Req::from_bytes(bytes).unwrap());
+    }
+
+    /// One copy of the request.
+    #[bench]
+    fn bench_decode_misaligned(b: &mut Bencher) {
+        let bytes = solve_req_misaligned();
+        b.bytes = bytes.len() as u64 - 1;
+        b.iter(|| Req::from_bytes(&bytes[1..]).unwrap());
+    }
+
+    /// No copies or allocations of the response.
+    #[bench]
+    fn bench_encode_in_buffer(b: &mut Bencher) {
+        let resp = Ok(RespKind::SolverResult {
+            solution: include_str!("sudoku_solution.txt").to_string(),
+        });
+        let mut buffer = MessageBuffer::new();
+        b.iter(|| {
+            buffer.send(
+                |resp_builder| build_resp(0, &resp, resp_builder),
+                &mut |bytes: &mut [u8]| {
+                    test::black_box(bytes);
+                },
+            )
+        });
+    }
+
+    /// One copy of the response, as well as allocations of the builder
+    /// and the output.
+    #[bench]
+    fn bench_encode_into_vec(b: &mut Bencher) {
+        let resp = Ok(RespKind::SolverResult {
+            solution: include_str!("sudoku_solution.txt").to_string(),
+        });
+        b.iter(|| {
+            let mut message = ::capnp::message::Builder::new_default();
+            build_resp(0, &resp, message.init_root::<api_capnp::resp::Builder>());
+            let mut bytes = Vec::new();
+            serialize::write_message(&mut bytes, &message).unwrap();
+            test::black_box(bytes);
+        });
+    }
 
     #[test]
     fn test_encode() {
@@ -229,4 +1278,434 @@ mod tests {
         let _bytes = resp.into_bytes();
         // TODO: assert_eq
     }
+
+    #[test]
+    fn test_decode_solve_assuming() {
+        let mut message = ::capnp::message::Builder::new_default();
+        {
+            let mut req_builder = message.init_root::<api_capnp::req::Builder>();
+            req_builder.set_id(7);
+            req_builder.set_priority(api_capnp::Priority::High);
+            let mut solve_assuming = req_builder.init_solve_assuming_req();
+            solve_assuming.set_id(3);
+            let mut assumptions = solve_assuming.init_assumptions(1);
+            let mut assumption = assumptions.borrow().get(0);
+            assumption.set_row(1);
+            assumption.set_col(2);
+            assumption.set_digit(9);
+        }
+        let mut bytes = Vec::new();
+        serialize::write_message(&mut bytes, &message).unwrap();
+
+        let req = Req::from_bytes(&bytes).unwrap();
+        assert_eq!(req.id, 7);
+        assert_eq!(req.priority, Priority::High);
+        match req.kind {
+            ReqKind::SolveAssuming { id, assumptions } => {
+                assert_eq!(id, 3);
+                assert_eq!(assumptions.len(), 1);
+                assert_eq!(
+                    (assumptions[0].row, assumptions[0].col, assumptions[0].digit),
+                    (1, 2, 9)
+                );
+            }
+            _ => panic!("expected solve assuming request"),
+        }
+    }
+
+    #[test]
+    fn test_events_require_subscription() {
+        use std::sync::mpsc::channel;
+
+        let (tx, rx) = channel();
+        let mut callback = CapnpCallback::new(move |bytes: &mut [u8]| tx.send(bytes.to_vec()).unwrap());
+        let event = Event {
+            id: 3,
+            kind: EventKind::Solved,
+        };
+
+        callback.event(event);
+        assert!(rx.try_recv().is_err());
+
+        callback.call(Resp {
+            id: 0,
+            kind: Ok(RespKind::EventsSubscribed),
+        });
+        let _ = rx.try_recv().unwrap();
+
+        callback.event(event);
+        let bytes = rx.try_recv().unwrap();
+        let mut reader = BufReader::new(&bytes[..]);
+        let msg = serialize::read_message(&mut reader, ReaderOptions::new()).unwrap();
+        let resp_root = msg.get_root::<api_capnp::resp::Reader>().unwrap();
+        let event = match resp_root.which() {
+            Ok(api_capnp::resp::Event(event)) => event.unwrap(),
+            _ => panic!("expected event"),
+        };
+        assert_eq!(event.get_solver_id(), 3);
+        assert!(event.get_kind().unwrap() == api_capnp::EventKind::Solved);
+    }
+
+    #[test]
+    fn test_batch_subscribes_to_events() {
+        use std::sync::mpsc::channel;
+
+        let (tx, rx) = channel();
+        let mut callback = CapnpCallback::new(move |bytes: &mut [u8]| tx.send(bytes.to_vec()).unwrap());
+        let event = Event {
+            id: 3,
+            kind: EventKind::Solved,
+        };
+
+        callback.call(Resp {
+            id: 0,
+            kind: Ok(RespKind::Batch {
+                results: vec![Err(ErrorKind::Overloaded.into())],
+            }),
+        });
+        let _ = rx.try_recv().unwrap();
+        callback.event(event);
+        assert!(rx.try_recv().is_err());
+
+        callback.call(Resp {
+            id: 1,
+            kind: Ok(RespKind::Batch {
+                results: vec![
+                    Ok(RespKind::SolverList { ids: vec![] }),
+                    Ok(RespKind::EventsSubscribed),
+                ],
+            }),
+        });
+        let _ = rx.try_recv().unwrap();
+        callback.event(event);
+        assert!(rx.try_recv().is_ok());
+    }
+
+    #[test]
+    fn test_encode_solver_list() {
+        let resp = Resp {
+            id: 1,
+            kind: Ok(RespKind::SolverList { ids: vec![0, 2, 5] }),
+        };
+        let bytes = resp.into_bytes();
+
+        let mut reader = BufReader::new(&bytes[..]);
+        let msg = serialize::read_message(&mut reader, ReaderOptions::new()).unwrap();
+        let resp_root = msg.get_root::<api_capnp::resp::Reader>().unwrap();
+        assert_eq!(resp_root.get_id(), 1);
+
+        let ok_resp = match resp_root.which() {
+            Ok(api_capnp::resp::Ok(ok_resp)) => ok_resp.unwrap(),
+            _ => panic!("expected ok response"),
+        };
+        let ids = match ok_resp.which() {
+            Ok(api_capnp::ok_resp::ListSolversResp(resp)) => resp.unwrap().get_ids().unwrap(),
+            _ => panic!("expected list solvers response"),
+        };
+        let ids: Vec<u32> = (0..ids.len()).map(|i| ids.get(i)).collect();
+        assert_eq!(ids, vec![0, 2, 5]);
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let decode = |bytes: Vec<u8>| {
+            let mut reader = BufReader::new(&bytes[..]);
+            let msg = serialize::read_message(&mut reader, ReaderOptions::new()).unwrap();
+            let resp_root = msg.get_root::<api_capnp::resp::Reader>().unwrap();
+            let code = match resp_root.which() {
+                Ok(api_capnp::resp::Err(code)) => code,
+                _ => panic!("expected error"),
+            };
+            let detail = resp_root.get_err_detail().unwrap();
+            (
+                code,
+                detail.get_message().unwrap().to_string(),
+                detail.get_solver_id(),
+            )
+        };
+        let resp = |e: Error| Resp { id: 0, kind: Err(e) };
+
+        let not_available = context::Error::from(context::ErrorKind::NotAvailable(7));
+        let (code, message, solver_id) = decode(resp(not_available.into()).into_bytes());
+        assert_eq!(code, api_capnp::ErrorCode::NotAvailable as u32);
+        assert_eq!(code, 2);
+        assert_eq!(solver_id, 7);
+        assert_eq!(
+            message,
+            "solver with specified id not available at the moment or doesn't exist"
+        );
+
+        let internal = ErrorKind::Internal("oops".to_string());
+        let (code, message, _) = decode(resp(internal.into()).into_bytes());
+        assert_eq!(code, api_capnp::ErrorCode::Internal as u32);
+        assert_eq!(message, "internal error: oops");
+
+        let (code, _, _) = decode(resp(ErrorKind::Overloaded.into()).into_bytes());
+        assert_eq!(code, api_capnp::ErrorCode::Overloaded as u32);
+    }
+
+    #[test]
+    fn test_batch() {
+        let mut message = ::capnp::message::Builder::new_default();
+        {
+            let mut req_builder = message.init_root::<api_capnp::req::Builder>();
+            req_builder.set_id(5);
+            let mut batch = req_builder.init_batch_req();
+            batch.set_stop_on_error(true);
+            let mut reqs = batch.init_reqs(2);
+            reqs.borrow().get(0).set_list_solvers_req(());
+            reqs.borrow().get(1).init_solve_req().set_id(3);
+        }
+        let mut bytes = Vec::new();
+        serialize::write_message(&mut bytes, &message).unwrap();
+
+        let req = Req::from_bytes(&bytes).unwrap();
+        assert_eq!(req.id, 5);
+        match req.kind {
+            ReqKind::Batch {
+                ref kinds,
+                stop_on_error: true,
+            } => match (&kinds[0], &kinds[1]) {
+                (&ReqKind::ListSolvers, &ReqKind::Solve { id: 3 }) => {}
+                _ => panic!("unexpected batched requests"),
+            },
+            _ => panic!("expected batch request"),
+        }
+
+        let resp = Resp {
+            id: 5,
+            kind: Ok(RespKind::Batch {
+                results: vec![
+                    Ok(RespKind::SolverList { ids: vec![] }),
+                    Err(ErrorKind::Internal("oops".to_string()).into()),
+                ],
+            }),
+        };
+        let bytes = resp.into_bytes();
+
+        let mut reader = BufReader::new(&bytes[..]);
+        let msg = serialize::read_message(&mut reader, ReaderOptions::new()).unwrap();
+        let resp_root = msg.get_root::<api_capnp::resp::Reader>().unwrap();
+        let ok_resp = match resp_root.which() {
+            Ok(api_capnp::resp::Ok(ok_resp)) => ok_resp.unwrap(),
+            _ => panic!("expected ok response"),
+        };
+        let resps = match ok_resp.which() {
+            Ok(api_capnp::ok_resp::BatchResp(resp)) => resp.unwrap().get_resps().unwrap(),
+            _ => panic!("expected batch response"),
+        };
+        assert_eq!(resps.len(), 2);
+        assert_eq!(resps.get(1).get_id(), 1);
+        match resps.get(0).which() {
+            Ok(api_capnp::resp::Ok(_)) => {}
+            _ => panic!("expected ok response"),
+        }
+        match resps.get(1).which() {
+            Ok(api_capnp::resp::Err(0)) => {}
+            _ => panic!("expected internal error"),
+        }
+    }
+
+    /// Responses to the requests sent as bytes via `send_bytes`.
+    fn send_all(requests: Vec<Vec<u8>>) -> Vec<Resp> {
+        use std::sync::mpsc::channel;
+
+        let (tx, rx) = channel();
+        let count = requests.len();
+        let executor =
+            Executor::with_config(Config::default(), move |resp| tx.send(resp).unwrap());
+        for bytes in requests {
+            send_bytes(&executor, &bytes);
+        }
+        rx.iter().take(count).collect()
+    }
+
+    #[test]
+    fn test_bad_messages() {
+        // Request of a kind unknown to this version: patch the id and the
+        // union discriminant, the 1st `u32` and the 3rd `u16` of the data
+        // section right after the segment table and the root pointer.
+        let mut unknown = Word::words_to_bytes(&solve_req_words()).to_vec();
+        unknown[16] = 7;
+        unknown[16 + 4] = 99;
+        let truncated = Word::words_to_bytes(&solve_req_words())[..12].to_vec();
+
+        let resps = send_all(vec![unknown, truncated]);
+        assert_eq!(resps.len(), 2);
+        for (resp, id) in resps.into_iter().zip(vec![7, 0]) {
+            assert_eq!(resp.id, id);
+            match resp.kind {
+                Err(ref e) if error_code(e) == api_capnp::ErrorCode::BadMessage => {}
+                _ => panic!("expected bad message error"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_handshake() {
+        use executor::{FEATURES, PROTOCOL_VERSION};
+
+        let mut message = ::capnp::message::Builder::new_default();
+        build_req(
+            &Req {
+                id: 1,
+                kind: ReqKind::Handshake {
+                    version: 1,
+                    features: vec!["batch".to_string(), "teleport".to_string()],
+                },
+                priority: Priority::Normal,
+                report_progress: false,
+            },
+            message.init_root(),
+        );
+        let bytes = Word::words_to_bytes(&serialize::write_message_to_words(&message)).to_vec();
+        match Req::from_bytes(&bytes).unwrap().kind {
+            ReqKind::Handshake { version: 1, ref features } => {
+                assert_eq!(*features, vec!["batch".to_string(), "teleport".to_string()])
+            }
+            _ => panic!("expected handshake request"),
+        }
+
+        let bytes = send_all(vec![bytes]).pop().unwrap().into_bytes();
+        let msg = serialize::read_message(&mut &bytes[..], ReaderOptions::new()).unwrap();
+        let resp_root = msg.get_root::<api_capnp::resp::Reader>().unwrap();
+        let handshake = match resp_root.which() {
+            Ok(api_capnp::resp::Ok(ok_resp)) => match ok_resp.unwrap().which() {
+                Ok(api_capnp::ok_resp::HandshakeResp(resp)) => resp.unwrap(),
+                _ => panic!("expected handshake response"),
+            },
+            _ => panic!("expected ok response"),
+        };
+        assert_eq!(resp_root.get_id(), 1);
+        assert_eq!(handshake.get_protocol_version(), PROTOCOL_VERSION);
+        assert_eq!(handshake.get_error_codes_version(), ERROR_CODES_VERSION);
+        let features = handshake.get_features().unwrap();
+        assert_eq!(features.len() as usize, FEATURES.len());
+        for (i, feature) in FEATURES.iter().enumerate() {
+            assert_eq!(features.get(i as u32).unwrap(), *feature);
+        }
+    }
+
+    #[test]
+    fn test_init_with_data() {
+        use std::sync::Mutex;
+        use ffi::{SOLVER_INVALID_ARGUMENT, SOLVER_OK};
+
+        extern "C" fn recv(user_data: *mut c_void, msg: *const u8, msg_len: usize) {
+            let received = unsafe { &*(user_data as *const Mutex<Vec<Vec<u8>>>) };
+            let msg = unsafe { ::std::slice::from_raw_parts(msg, msg_len) };
+            received.lock().unwrap().push(msg.to_vec());
+        }
+
+        let received: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+        let options = ExecutorOptions {
+            workers: 2,
+            max_pending: 0,
+            overflow: SOLVER_OVERFLOW_BLOCK,
+            max_bytes: 0,
+            max_solvers: 0,
+            journal_path: ptr::null(),
+        };
+        let this = capnp_init_with_data(recv, &received as *const _ as *mut c_void, options);
+        assert!(!this.is_null());
+
+        let words = solve_req_words();
+        let msg = Word::words_to_bytes(&words);
+        assert_eq!(capnp_send(this, msg.as_ptr(), msg.len()), SOLVER_OK);
+        assert_eq!(capnp_send(this, ptr::null(), 0), SOLVER_INVALID_ARGUMENT);
+        assert_eq!(capnp_destroy(this, false), SOLVER_OK);
+
+        // Solver 3 doesn't exist, but the request is responded anyway.
+        let received = received.into_inner().unwrap();
+        assert_eq!(received.len(), 1);
+        let msg = serialize::read_message(&mut &received[0][..], ReaderOptions::new()).unwrap();
+        let resp = Resp::from_reader(msg.get_root().unwrap()).unwrap();
+        assert!(resp.kind.is_err());
+    }
+
+    #[test]
+    fn test_init_with_config() {
+        use std::sync::mpsc::channel;
+        use executor::Interceptor;
+        use ffi::SOLVER_OK;
+
+        struct Deny;
+
+        impl Interceptor for Deny {
+            fn before(&self, _req: &Req) -> Result<()> {
+                bail!(ErrorKind::Internal("access denied".to_string()))
+            }
+        }
+
+        let (tx, rx) = channel();
+        let config = Config {
+            interceptors: vec![Box::new(Deny)],
+            ..Config::default()
+        };
+        let this = init_with_config(move |bytes: &mut [u8]| tx.send(bytes.to_vec()).unwrap(), config);
+        assert!(!this.is_null());
+
+        let words = solve_req_words();
+        let msg = Word::words_to_bytes(&words);
+        assert_eq!(capnp_send(this, msg.as_ptr(), msg.len()), SOLVER_OK);
+        assert_eq!(capnp_destroy(this, false), SOLVER_OK);
+
+        let bytes = rx.recv().unwrap();
+        let msg = serialize::read_message(&mut &bytes[..], ReaderOptions::new()).unwrap();
+        let resp = Resp::from_reader(msg.get_root().unwrap()).unwrap();
+        match resp.kind {
+            Err(Error(ErrorKind::Msg(ref message), _)) => assert!(message.contains("access denied")),
+            _ => panic!("expected access denied"),
+        }
+    }
+
+    #[test]
+    fn test_init_with_journal() {
+        use std::ffi::CString;
+        use std::fs::{self, File};
+        use std::io::BufReader;
+        use std::process;
+        use std::sync::Mutex;
+        use std::sync::mpsc::{channel, Sender};
+        use ffi::SOLVER_OK;
+        use journal;
+
+        let path = ::std::env::temp_dir().join(format!("turbosolver-journal-{}", process::id()));
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+
+        extern "C" fn recv(user_data: *mut c_void, _msg: *const u8, _msg_len: usize) {
+            let received = unsafe { &*(user_data as *const Mutex<Sender<()>>) };
+            received.lock().unwrap().send(()).unwrap();
+        }
+
+        let (tx, rx) = channel::<()>();
+        let received = Mutex::new(tx);
+        let options = ExecutorOptions {
+            workers: 1,
+            max_pending: 0,
+            overflow: SOLVER_OVERFLOW_BLOCK,
+            max_bytes: 0,
+            max_solvers: 0,
+            journal_path: c_path.as_ptr(),
+        };
+        let this = capnp_init_with_data(recv, &received as *const _ as *mut c_void, options);
+        assert!(!this.is_null());
+
+        let words = solve_req_words();
+        let msg = Word::words_to_bytes(&words);
+        assert_eq!(capnp_send(this, msg.as_ptr(), msg.len()), SOLVER_OK);
+        assert_eq!(capnp_destroy(this, false), SOLVER_OK);
+        rx.recv().unwrap();
+
+        // The request is recorded, and replays the same way.
+        let open = || BufReader::new(File::open(&path).unwrap());
+        let msg = serialize::read_message(&mut open(), ReaderOptions::new()).unwrap();
+        let entry = msg.get_root::<api_capnp::journal_entry::Reader>().unwrap();
+        match entry.get_req().unwrap().which() {
+            Ok(api_capnp::req::SolveReq(req)) => assert_eq!(req.unwrap().get_id(), 3),
+            _ => panic!("expected solve request"),
+        }
+        assert!(journal::replay(open(), Config::default()).unwrap().is_empty());
+        fs::remove_file(&path).unwrap();
+    }
 }
@@ -0,0 +1,247 @@
+//! Client - asynchronous API on top of the `Executor`.
+//!
+//! Takes care of correlating responses with requests, so many requests
+//! can be in flight at once.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use futures::{Async, Future, Poll};
+use futures::sync::oneshot;
+use context::{lock, Event};
+use executor::{Config, Executor, ExecutorCallback, Priority, Req, ReqKind, Resp, RespKind};
+use solver::Progress;
+use error::*;
+
+/// Unsolicited message from the executor, see `Client::subscribe`.
+#[derive(Clone, Debug)]
+pub enum Notification {
+    Event(Event),
+    /// A worker has been restarted after a panic with this message.
+    WorkerRestarted(String),
+}
+
+type Pending = Arc<Mutex<HashMap<usize, oneshot::Sender<Result<RespKind>>>>>;
+type Subscribers = Arc<Mutex<Vec<Sender<Notification>>>>;
+type ProgressSenders = Arc<Mutex<HashMap<usize, Sender<Progress>>>>;
+
+struct ClientCallback {
+    pending: Pending,
+    subscribers: Subscribers,
+    progress: ProgressSenders,
+}
+
+impl ClientCallback {
+    fn broadcast(&mut self, notification: Notification) {
+        // Drop subscribers whose receivers are gone.
+        lock(&self.subscribers).retain(|subscriber| subscriber.send(notification.clone()).is_ok());
+    }
+}
+
+impl ExecutorCallback for ClientCallback {
+    fn call(&mut self, resp: Resp) {
+        // Progress receiver gets disconnected before the response arrives.
+        lock(&self.progress).remove(&resp.id);
+
+        let resp_tx = lock(&self.pending).remove(&resp.id);
+        if let Some(resp_tx) = resp_tx {
+            // Response future might be already dropped, it's fine.
+            let _ = resp_tx.send(resp.kind);
+        }
+    }
+
+    fn event(&mut self, event: Event) {
+        self.broadcast(Notification::Event(event));
+    }
+
+    fn worker_restarted(&mut self, message: &str) {
+        self.broadcast(Notification::WorkerRestarted(message.to_string()));
+    }
+
+    fn progress(&mut self, id: usize, progress: Progress) {
+        if let Some(progress_tx) = lock(&self.progress).get(&id) {
+            let _ = progress_tx.send(progress);
+        }
+    }
+}
+
+/// Future of the response to a single request.
+///
+/// Use `Future::wait` to block until the response arrives.
+pub struct ResponseFuture {
+    resp_rx: oneshot::Receiver<Result<RespKind>>,
+}
+
+impl Future for ResponseFuture {
+    type Item = RespKind;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<RespKind, Error> {
+        match self.resp_rx.poll() {
+            Ok(Async::Ready(Ok(resp_kind))) => Ok(Async::Ready(resp_kind)),
+            Ok(Async::Ready(Err(e))) => Err(e),
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Err(oneshot::Canceled) => {
+                bail!(ErrorKind::Internal("request was dropped by the executor".to_string()))
+            }
+        }
+    }
+}
+
+/// Client of an `Executor`, which it owns.
+///
+/// Can be shared between threads.
+pub struct Client {
+    executor: Executor,
+    pending: Pending,
+    subscribers: Subscribers,
+    progress: ProgressSenders,
+    next_msg_id: AtomicUsize,
+}
+
+impl Client {
+    pub fn new() -> Client {
+        Client::with_config(Config::default())
+    }
+
+    pub fn with_config(config: Config) -> Client {
+        let pending = Arc::new(Mutex::new(HashMap::new()));
+        let subscribers = Arc::new(Mutex::new(Vec::new()));
+        let progress = Arc::new(Mutex::new(HashMap::new()));
+        let executor = Executor::with_config(
+            config,
+            ClientCallback {
+                pending: Arc::clone(&pending),
+                subscribers: Arc::clone(&subscribers),
+                progress: Arc::clone(&progress),
+            },
+        );
+        Client {
+            executor,
+            pending,
+            subscribers,
+            progress,
+            next_msg_id: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn send(&self, kind: ReqKind) -> ResponseFuture {
+        self.send_with_priority(kind, Priority::default())
+    }
+
+    pub fn send_with_priority(&self, kind: ReqKind, priority: Priority) -> ResponseFuture {
+        let id = self.next_msg_id.fetch_add(1, Ordering::SeqCst);
+        self.send_req(id, kind, priority, false)
+    }
+
+    /// Same as `send_with_priority`, but also returns receiver of the progress
+    /// of the request. It is disconnected once the response arrives.
+    pub fn send_with_progress(
+        &self,
+        kind: ReqKind,
+        priority: Priority,
+    ) -> (ResponseFuture, Receiver<Progress>) {
+        let id = self.next_msg_id.fetch_add(1, Ordering::SeqCst);
+        let (progress_tx, progress_rx) = channel();
+        lock(&self.progress).insert(id, progress_tx);
+        (self.send_req(id, kind, priority, true), progress_rx)
+    }
+
+    fn send_req(
+        &self,
+        id: usize,
+        kind: ReqKind,
+        priority: Priority,
+        report_progress: bool,
+    ) -> ResponseFuture {
+        let (resp_tx, resp_rx) = oneshot::channel();
+        lock(&self.pending).insert(id, resp_tx);
+        self.executor.send(Req {
+            id,
+            kind,
+            priority,
+            report_progress,
+        });
+        ResponseFuture { resp_rx }
+    }
+
+    /// Returns receiver of all notifications from now on.
+    pub fn subscribe(&self) -> Receiver<Notification> {
+        let (tx, rx) = channel();
+        lock(&self.subscribers).push(tx);
+        rx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future;
+    use context::EventKind;
+
+    #[test]
+    fn concurrent_requests() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let expected_solution = include_str!("sudoku_solution.txt");
+        let client = Client::with_config(Config {
+            workers: 4,
+            ..Config::default()
+        });
+
+        let creates = (0..8).map(|_| client.send(ReqKind::CreateSolver { grid: sudoku_grid.to_string() }));
+        let ids: Vec<usize> = future::join_all(creates)
+            .wait()
+            .unwrap()
+            .into_iter()
+            .map(|resp_kind| match resp_kind {
+                RespKind::SolverCreated { id } => id,
+                _ => panic!("expected SolverCreated"),
+            })
+            .collect();
+
+        let solves = ids.iter().map(|&id| client.send(ReqKind::Solve { id }));
+        for resp_kind in future::join_all(solves).wait().unwrap() {
+            match resp_kind {
+                RespKind::SolverResult { solution } => assert_eq!(solution, expected_solution),
+                _ => panic!("expected SolverResult"),
+            }
+        }
+    }
+
+    #[test]
+    fn progress() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let client = Client::new();
+        client
+            .send(ReqKind::CreateSolver { grid: sudoku_grid.to_string() })
+            .wait()
+            .unwrap();
+
+        let (resp, progress) = client.send_with_progress(ReqKind::Solve { id: 0 }, Priority::Normal);
+        resp.wait().unwrap();
+        let progress: Vec<Progress> = progress.iter().collect();
+        assert_eq!(progress.last(), Some(&Progress { done: 1, total: 1 }));
+    }
+
+    #[test]
+    fn errors_and_notifications() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let client = Client::new();
+        let notifications = client.subscribe();
+
+        match client.send(ReqKind::Solve { id: 42 }).wait() {
+            Err(Error(ErrorKind::Context(_), _)) => {}
+            _ => panic!("expected context error"),
+        }
+
+        client
+            .send(ReqKind::CreateSolver { grid: sudoku_grid.to_string() })
+            .wait()
+            .unwrap();
+        match notifications.recv().unwrap() {
+            Notification::Event(event) => assert_eq!(event.kind, EventKind::Created),
+            _ => panic!("expected event"),
+        }
+    }
+}
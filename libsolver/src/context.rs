@@ -1,8 +1,9 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
 use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use std::mem;
-use solver::Solver;
+use solver::{Assignment, Progress, Puzzle, Solver};
 
 error_chain!{
     errors {
@@ -15,42 +16,280 @@ error_chain!{
         SolutionNotFound {
             description("solution for the specified grid couldn't be found")
         }
+        BadAssignment {
+            description("assignment is out of range")
+        }
+        QuotaExceeded {
+            description("solver couldn't be created without exceeding the quota")
+        }
+    }
+}
+
+/// Limits enforced by `Context`. `None` means unlimited.
+///
+/// Every `Context` represents a separate session, so `max_solvers`
+/// is effectively a per-session limit.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Limits {
+    /// Quota on the approximate amount of memory used by solvers, in bytes.
+    pub max_bytes: Option<usize>,
+    pub max_solvers: Option<usize>,
+}
+
+/// Resources currently used by `Context`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Usage {
+    /// Approximate amount of memory used by solvers, in bytes.
+    pub bytes: usize,
+    pub solvers: usize,
+}
+
+/// Kind of a lifecycle event.
+///
+/// There is no kind for evicted solvers, since nothing evicts them yet: a
+/// solver lives until it is destroyed, so `Destroyed` is always its last
+/// event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventKind {
+    Created,
+    Solved,
+    Destroyed,
+}
+
+/// Lifecycle event of a solver.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Event {
+    pub id: usize,
+    pub kind: EventKind,
+}
+
+/// Listener of solver lifecycle events, see `Context::subscribe`.
+///
+/// Listeners are called synchronously on the thread that caused an event,
+/// so they should be quick and must not call back into `Context`.
+pub trait Listener: Send + Sync {
+    fn on_event(&self, event: Event);
+}
+
+impl<F: Fn(Event) + Send + Sync> Listener for F {
+    fn on_event(&self, event: Event) {
+        self(event)
+    }
+}
+
+/// Snapshot of the solver state, as returned by `Context::describe`.
+pub struct SolverInfo {
+    pub id: usize,
+    pub grid: String,
+    pub current_grid: String,
+    pub created: SystemTime,
+    pub last_used: SystemTime,
+    pub solved: bool,
+}
+
+/// Lock `mutex`, ignoring poisoning.
+///
+/// Panics are caught by the executor, so a panic while the lock was held
+/// must not render the context unusable. All the state guarded by locks
+/// here stays consistent between individual statements.
+pub fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Convert `time` into milliseconds since unix epoch. Used to
+/// pass timestamps over the wire.
+pub fn unix_millis(time: SystemTime) -> u64 {
+    let duration = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    duration.as_secs() * 1000 + (duration.subsec_nanos() / 1_000_000) as u64
+}
+
+/// Inverse of `unix_millis`.
+pub fn from_unix_millis(millis: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(millis)
+}
+
+struct Entry {
+    solver: Solver,
+    created: SystemTime,
+    last_used: SystemTime,
+}
+
+impl Entry {
+    /// Approximate amount of memory occupied by the entry, i.e. its slot
+    /// in the solvers map. Puzzle is not included, since it might be
+    /// shared with other entries.
+    fn approx_size(&self) -> usize {
+        map_slot_size::<usize, Option<Entry>>()
     }
 }
 
+/// Approximate amount of memory occupied by a slot of `HashMap<K, V>`.
+fn map_slot_size<K, V>() -> usize {
+    // The hash is stored along with the key and the value, and the map
+    // is never more than 10/11 full.
+    (mem::size_of::<u64>() + mem::size_of::<K>() + mem::size_of::<V>()) * 11 / 10
+}
+
 pub struct Context {
     next_id: AtomicUsize,
-    solvers: Mutex<HashMap<usize, Solver>>,
+    /// Entries of solvers by their ids, `None` while the solver is busy.
+    solvers: Mutex<HashMap<usize, Option<Entry>>>,
+    limits: Limits,
+    // Accounted separately from `solvers`, since entries are taken out
+    // of the map while they are busy.
+    usage: Mutex<Usage>,
+    listeners: Mutex<Vec<Box<Listener>>>,
+    /// Locked before `usage` when both are needed.
+    puzzles: Mutex<Puzzles>,
+}
+
+/// Puzzles of alive solvers.
+///
+/// Whether a puzzle is still used is decided by counting its solvers
+/// rather than by the count of `Arc` references, since temporary copies
+/// of solvers, e.g. in `Context::clone_solver`, hold references as well.
+#[derive(Default)]
+struct Puzzles {
+    /// Puzzles by their keys, if interning is enabled.
+    interned: Option<HashMap<Vec<u8>, Weak<Puzzle>>>,
+    /// Number of solvers using a puzzle, by its address. Without interning
+    /// equivalent puzzles aren't shared, so their keys can't tell them apart.
+    users: HashMap<usize, usize>,
+}
+
+impl Puzzles {
+    /// Returns the interned puzzle equivalent to `puzzle` if there is one,
+    /// otherwise `puzzle` itself, interned if interning is enabled.
+    fn intern(&mut self, puzzle: Puzzle) -> Arc<Puzzle> {
+        let interned = match self.interned {
+            Some(ref mut interned) => interned,
+            None => return Arc::new(puzzle),
+        };
+
+        let key = puzzle.key();
+        if let Some(puzzle) = interned.get(&key).and_then(Weak::upgrade) {
+            return puzzle;
+        }
+        let puzzle = Arc::new(puzzle);
+        interned.insert(key, Arc::downgrade(&puzzle));
+        puzzle
+    }
+
+    /// Approximate amount of memory occupied by `puzzle`, including its
+    /// entries here. It is accounted only once, no matter how many solvers
+    /// share it.
+    fn size(&self, puzzle: &Puzzle) -> usize {
+        let mut bytes = puzzle.heap_size() + map_slot_size::<usize, usize>();
+        if self.interned.is_some() {
+            bytes += map_slot_size::<Vec<u8>, Weak<Puzzle>>() + puzzle.key().len();
+        }
+        bytes
+    }
+
+    fn is_used(&self, puzzle: &Arc<Puzzle>) -> bool {
+        self.users.contains_key(&address(puzzle))
+    }
+
+    fn add_user(&mut self, puzzle: &Arc<Puzzle>) {
+        *self.users.entry(address(puzzle)).or_insert(0) += 1;
+    }
+
+    /// Returns whether that was the last user of `puzzle`.
+    fn remove_user(&mut self, puzzle: &Arc<Puzzle>) -> bool {
+        let address = address(puzzle);
+        let is_last = {
+            let users = self.users
+                .get_mut(&address)
+                .expect("puzzle of an alive solver should have users");
+            *users -= 1;
+            *users == 0
+        };
+        if is_last {
+            self.users.remove(&address);
+            self.forget(puzzle);
+        }
+        is_last
+    }
+
+    /// Remove `puzzle`, which is no longer used, from the interning table.
+    fn forget(&mut self, puzzle: &Puzzle) {
+        if let Some(ref mut interned) = self.interned {
+            interned.remove(&puzzle.key());
+        }
+    }
+}
+
+fn address(puzzle: &Arc<Puzzle>) -> usize {
+    &**puzzle as *const Puzzle as usize
 }
 
 impl Context {
     pub fn new() -> Context {
+        Context::with_limits(Limits::default())
+    }
+
+    pub fn with_limits(limits: Limits) -> Context {
         Context {
             next_id: AtomicUsize::new(0),
             solvers: Mutex::new(HashMap::new()),
+            limits,
+            usage: Mutex::new(Usage::default()),
+            listeners: Mutex::new(Vec::new()),
+            puzzles: Mutex::new(Puzzles::default()),
         }
     }
 
+    /// Create a context in which solvers created from equivalent grids share
+    /// the parsed grid and its solution. Each solver still has its own state.
+    pub fn with_interning(limits: Limits) -> Context {
+        Context {
+            puzzles: Mutex::new(Puzzles {
+                interned: Some(HashMap::new()),
+                users: HashMap::new(),
+            }),
+            ..Context::with_limits(limits)
+        }
+    }
+
+    /// Register `listener` to be notified about lifecycle events of all solvers.
+    pub fn subscribe<L: Listener + 'static>(&self, listener: L) {
+        lock(&self.listeners).push(Box::new(listener));
+    }
+
     pub fn new_solver(&self, grid: &str) -> Result<usize> {
-        let new_solver = Solver::from_str(grid).chain_err(|| ErrorKind::BadGrid)?;
-        let solver_id = self.next_id.fetch_add(1, Ordering::SeqCst);
-        let mut solvers = self.solvers.lock().unwrap();
-        solvers.insert(solver_id, new_solver);
-        Ok(solver_id)
+        let puzzle = Puzzle::from_str(grid).chain_err(|| ErrorKind::BadGrid)?;
+        self.insert(|puzzles| Solver::new(puzzles.intern(puzzle)))
+    }
+
+    /// Create a new solver which is a copy of the solver with the given `id`.
+    pub fn clone_solver(&self, id: usize) -> Result<usize> {
+        let solver = self.with_solver(id, |solver| solver.clone())?;
+        self.insert(|_| solver)
     }
 
     pub fn solve(&self, id: usize) -> Result<String> {
-        let mut solver = {
-            let mut solvers = self.solvers.lock().unwrap();
-            match solvers.remove(&id) {
-                Some(solver) => solver,
-                None => bail!(ErrorKind::NotAvailable(id)),
-            }
-        };
+        self.solve_with_progress(id, &mut |_| {})
+    }
+
+    /// Same as `solve`, but reports progress to `progress`.
+    pub fn solve_with_progress(&self, id: usize, progress: &mut FnMut(Progress)) -> Result<String> {
+        let maybe_solution = self.with_solver(id, |solver| solver.solve_with_progress(progress))?;
 
-        let maybe_solution = solver.solve();
+        if let Some(solution) = maybe_solution {
+            self.emit(id, EventKind::Solved);
+            Ok(solution)
+        } else {
+            bail!(ErrorKind::SolutionNotFound);
+        }
+    }
+
+    /// Solve as if `assumptions` were part of the grid, without changing the solver.
+    pub fn solve_assuming(&self, id: usize, assumptions: &[Assignment]) -> Result<String> {
+        if !assumptions.iter().all(Assignment::is_valid) {
+            bail!(ErrorKind::BadAssignment);
+        }
 
-        self.solvers.lock().unwrap().insert(id, solver);
+        let maybe_solution = self.with_solver(id, |solver| solver.solve_assuming(assumptions))?;
 
         if let Some(solution) = maybe_solution {
             Ok(solution)
@@ -59,14 +298,411 @@ impl Context {
         }
     }
 
+    /// Up to `count` cells of the solution which aren't filled in yet, see
+    /// `Solver::hints`.
+    pub fn hints(&self, id: usize, count: usize) -> Result<Vec<Assignment>> {
+        let maybe_hints = self.with_solver(id, |solver| solver.hints(count))?;
+
+        if let Some(hints) = maybe_hints {
+            Ok(hints)
+        } else {
+            bail!(ErrorKind::SolutionNotFound);
+        }
+    }
+
     pub fn destroy(&self, id: usize) -> Result<()> {
-        let mut solvers = self.solvers.lock().unwrap();
-        match solvers.remove(&id) {
-            Some(solver) => {
-                mem::drop(solver);
-                Ok(())
+        let entry = {
+            let mut solvers = lock(&self.solvers);
+            match solvers.remove(&id) {
+                Some(Some(entry)) => entry,
+                Some(None) => {
+                    // Busy solvers can't be destroyed, put the placeholder back.
+                    solvers.insert(id, None);
+                    bail!(ErrorKind::NotAvailable(id))
+                }
+                None => bail!(ErrorKind::NotAvailable(id)),
+            }
+        };
+
+        let mut bytes = entry.approx_size();
+        let puzzle = entry.solver.into_puzzle();
+        {
+            let mut puzzles = lock(&self.puzzles);
+            if puzzles.remove_user(&puzzle) {
+                bytes += puzzles.size(&puzzle);
             }
-            None => bail!(ErrorKind::NotAvailable(id)),
+            self.release(bytes);
         }
+        self.emit(id, EventKind::Destroyed);
+        Ok(())
+    }
+
+    /// Returns ids of all solvers in ascending order, including the ones
+    /// that are busy at the moment.
+    pub fn list(&self) -> Vec<usize> {
+        let solvers = lock(&self.solvers);
+        let mut ids: Vec<usize> = solvers.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Snapshot of the solver state. Fails with `NotAvailable` while the
+    /// solver is busy.
+    pub fn describe(&self, id: usize) -> Result<SolverInfo> {
+        let solvers = lock(&self.solvers);
+        match solvers.get(&id) {
+            Some(&Some(ref entry)) => Ok(SolverInfo {
+                id,
+                grid: entry.solver.grid(),
+                current_grid: entry.solver.current_grid(),
+                created: entry.created,
+                last_used: entry.last_used,
+                solved: entry.solver.is_solved(),
+            }),
+            Some(&None) | None => bail!(ErrorKind::NotAvailable(id)),
+        }
+    }
+
+    pub fn usage(&self) -> Usage {
+        *lock(&self.usage)
+    }
+
+    pub fn limits(&self) -> Limits {
+        self.limits
+    }
+
+    /// Insert the solver made by `make`, which might share a puzzle with
+    /// others. The puzzles are locked while it is made, so that a puzzle
+    /// can't be released in the meantime.
+    fn insert<F: FnOnce(&mut Puzzles) -> Solver>(&self, make: F) -> Result<usize> {
+        let now = SystemTime::now();
+        let entry = {
+            let mut puzzles = lock(&self.puzzles);
+            let entry = Entry {
+                solver: make(&mut puzzles),
+                created: now,
+                last_used: now,
+            };
+            let is_new = !puzzles.is_used(entry.solver.puzzle());
+            let mut bytes = entry.approx_size();
+            if is_new {
+                bytes += puzzles.size(entry.solver.puzzle());
+            }
+            if let Err(e) = self.reserve(bytes) {
+                if is_new {
+                    puzzles.forget(entry.solver.puzzle());
+                }
+                return Err(e);
+            }
+            puzzles.add_user(entry.solver.puzzle());
+            entry
+        };
+
+        let solver_id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        lock(&self.solvers).insert(solver_id, Some(entry));
+        self.emit(solver_id, EventKind::Created);
+        Ok(solver_id)
+    }
+
+    fn emit(&self, id: usize, kind: EventKind) {
+        let event = Event { id, kind };
+        for listener in lock(&self.listeners).iter() {
+            listener.on_event(event);
+        }
+    }
+
+    /// Account for a new solver occupying `bytes`, unless that would exceed the limits.
+    fn reserve(&self, bytes: usize) -> Result<()> {
+        let mut usage = lock(&self.usage);
+        let too_many_solvers = self.limits
+            .max_solvers
+            .map_or(false, |max_solvers| usage.solvers + 1 > max_solvers);
+        let too_many_bytes = self.limits
+            .max_bytes
+            .map_or(false, |max_bytes| usage.bytes + bytes > max_bytes);
+        if too_many_solvers || too_many_bytes {
+            bail!(ErrorKind::QuotaExceeded);
+        }
+
+        usage.solvers += 1;
+        usage.bytes += bytes;
+        Ok(())
+    }
+
+    fn release(&self, bytes: usize) {
+        let mut usage = lock(&self.usage);
+        usage.solvers -= 1;
+        usage.bytes -= bytes;
+    }
+
+    /// Take the solver out, run `f` on it and then put it back.
+    ///
+    /// The lock isn't held while `f` is running, so the solver is
+    /// not available to others in the meantime, although it is still listed.
+    fn with_solver<T, F: FnOnce(&mut Solver) -> T>(&self, id: usize, f: F) -> Result<T> {
+        let entry = {
+            let mut solvers = lock(&self.solvers);
+            match solvers.get_mut(&id).and_then(Option::take) {
+                Some(entry) => entry,
+                None => bail!(ErrorKind::NotAvailable(id)),
+            }
+        };
+
+        // Put the solver back even if `f` panics, otherwise it would stay
+        // busy forever and could never be destroyed.
+        let mut checkout = Checkout {
+            ctx: self,
+            id,
+            entry: Some(entry),
+        };
+        let result = f(&mut checkout.entry.as_mut().unwrap().solver);
+
+        Ok(result)
+    }
+}
+
+/// Solver taken out by `Context::with_solver`. Puts it back when dropped.
+struct Checkout<'a> {
+    ctx: &'a Context,
+    id: usize,
+    entry: Option<Entry>,
+}
+
+impl<'a> Drop for Checkout<'a> {
+    fn drop(&mut self) {
+        if let Some(mut entry) = self.entry.take() {
+            entry.last_used = SystemTime::now();
+            lock(&self.ctx.solvers).insert(self.id, Some(entry));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solver_limit() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let ctx = Context::with_limits(Limits {
+            max_solvers: Some(1),
+            ..Limits::default()
+        });
+
+        let id = ctx.new_solver(sudoku_grid).unwrap();
+        match ctx.clone_solver(id) {
+            Err(Error(ErrorKind::QuotaExceeded, _)) => {}
+            _ => panic!("expected QuotaExceeded"),
+        }
+
+        ctx.destroy(id).unwrap();
+        ctx.new_solver(sudoku_grid).unwrap();
+    }
+
+    #[test]
+    fn events() {
+        use std::sync::mpsc::channel;
+
+        let sudoku_grid = include_str!("sudoku.txt");
+        let ctx = Context::new();
+        let (tx, rx) = channel();
+        let tx = Mutex::new(tx);
+        ctx.subscribe(move |event| tx.lock().unwrap().send(event).unwrap());
+
+        let id = ctx.new_solver(sudoku_grid).unwrap();
+        let clone_id = ctx.clone_solver(id).unwrap();
+        ctx.solve(id).unwrap();
+        ctx.destroy(id).unwrap();
+
+        let events: Vec<Event> = rx.try_iter().collect();
+        assert_eq!(
+            events,
+            vec![
+                Event { id, kind: EventKind::Created },
+                Event { id: clone_id, kind: EventKind::Created },
+                Event { id, kind: EventKind::Solved },
+                Event { id, kind: EventKind::Destroyed },
+            ]
+        );
+    }
+
+    #[test]
+    fn interning() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        // The same grid, formatted differently.
+        let equivalent_grid = sudoku_grid.replace('|', "");
+
+        let ctx = Context::with_interning(Limits::default());
+        let first = ctx.new_solver(sudoku_grid).unwrap();
+        let first_bytes = ctx.usage().bytes;
+        let second = ctx.new_solver(&equivalent_grid).unwrap();
+        let shared_bytes = ctx.usage().bytes - first_bytes;
+        assert!(shared_bytes < first_bytes);
+
+        // Each solver keeps its own state.
+        let solution = ctx.solve(first).unwrap();
+        assert!(ctx.describe(first).unwrap().solved);
+        assert!(!ctx.describe(second).unwrap().solved);
+        assert_eq!(ctx.solve(second).unwrap(), solution);
+
+        // The puzzle is still used by the second solver.
+        ctx.destroy(first).unwrap();
+        assert_eq!(ctx.usage().bytes, first_bytes);
+        ctx.destroy(second).unwrap();
+        assert_eq!(ctx.usage().bytes, 0);
+
+        // Puzzle is created anew once all its solvers are gone.
+        ctx.new_solver(sudoku_grid).unwrap();
+        assert_eq!(ctx.usage().bytes, first_bytes);
+    }
+
+    #[test]
+    fn concurrent_destroys() {
+        use std::thread;
+
+        let sudoku_grid = include_str!("sudoku.txt");
+        let ctx = Arc::new(Context::with_interning(Limits::default()));
+        for _ in 0..100 {
+            let ids: Vec<usize> = (0..4).map(|_| ctx.new_solver(sudoku_grid).unwrap()).collect();
+            let threads: Vec<_> = ids.into_iter()
+                .map(|id| {
+                    let ctx = ctx.clone();
+                    thread::spawn(move || ctx.destroy(id).unwrap())
+                })
+                .collect();
+            for thread in threads {
+                thread.join().unwrap();
+            }
+
+            let usage = ctx.usage();
+            assert_eq!(usage.solvers, 0);
+            assert_eq!(usage.bytes, 0);
+            assert!(lock(&ctx.puzzles).users.is_empty());
+            assert!(lock(&ctx.puzzles).interned.as_ref().unwrap().is_empty());
+        }
+    }
+
+    #[test]
+    fn destroy_during_clone() {
+        use std::thread;
+
+        let sudoku_grid = include_str!("sudoku.txt");
+        // Clones fail the quota, but hold the puzzle for a moment.
+        let ctx = Arc::new(Context::with_limits(Limits {
+            max_solvers: Some(1),
+            ..Limits::default()
+        }));
+        for _ in 0..100 {
+            let id = ctx.new_solver(sudoku_grid).unwrap();
+            let cloning = {
+                let ctx = ctx.clone();
+                thread::spawn(move || ctx.clone_solver(id).is_err())
+            };
+            // Fails while the solver is being cloned.
+            while ctx.destroy(id).is_err() {}
+            assert!(cloning.join().unwrap());
+
+            assert_eq!(ctx.usage().bytes, 0);
+            assert!(lock(&ctx.puzzles).users.is_empty());
+        }
+    }
+
+    #[test]
+    fn quota_exceeded_is_not_interned() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let ctx = Context::with_interning(Limits {
+            max_solvers: Some(0),
+            ..Limits::default()
+        });
+        assert!(ctx.new_solver(sudoku_grid).is_err());
+        assert_eq!(ctx.usage().bytes, 0);
+        assert!(lock(&ctx.puzzles).interned.as_ref().unwrap().is_empty());
+    }
+
+    #[test]
+    fn poisoned_lock() {
+        use std::panic;
+
+        let sudoku_grid = include_str!("sudoku.txt");
+        let ctx = Context::new();
+        let id = ctx.new_solver(sudoku_grid).unwrap();
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let _solvers = lock(&ctx.solvers);
+            panic!("poison");
+        }));
+        assert!(result.is_err());
+        assert!(ctx.solvers.is_poisoned());
+
+        assert_eq!(ctx.list(), vec![id]);
+        ctx.solve(id).unwrap();
+    }
+
+    #[test]
+    fn busy_solvers_are_listed() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let ctx = Context::new();
+        let id = ctx.new_solver(sudoku_grid).unwrap();
+
+        let (listed, described, destroyed) = ctx.with_solver(id, |_| {
+            (ctx.list(), ctx.describe(id).is_ok(), ctx.destroy(id).is_ok())
+        }).unwrap();
+        assert_eq!(listed, vec![id]);
+        assert!(!described);
+        assert!(!destroyed);
+
+        assert!(ctx.describe(id).is_ok());
+        ctx.destroy(id).unwrap();
+        assert!(ctx.list().is_empty());
+    }
+
+    #[test]
+    fn panicking_solver_is_put_back() {
+        use std::panic;
+
+        let sudoku_grid = include_str!("sudoku.txt");
+        let ctx = Context::new();
+        let id = ctx.new_solver(sudoku_grid).unwrap();
+        let usage = ctx.usage();
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            ctx.with_solver(id, |_| panic!("solver failed"))
+        }));
+        assert!(result.is_err());
+
+        assert_eq!(ctx.list(), vec![id]);
+        assert!(ctx.describe(id).is_ok());
+        assert_eq!(ctx.usage().solvers, usage.solvers);
+        assert_eq!(ctx.usage().bytes, usage.bytes);
+
+        ctx.destroy(id).unwrap();
+        assert!(ctx.list().is_empty());
+        assert_eq!(ctx.usage().solvers, 0);
+    }
+
+    #[test]
+    fn byte_quota() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let ctx = Context::new();
+        let id = ctx.new_solver(sudoku_grid).unwrap();
+        let solver_bytes = ctx.usage().bytes;
+        assert!(solver_bytes > 0);
+        ctx.destroy(id).unwrap();
+        assert_eq!(ctx.usage().bytes, 0);
+
+        let ctx = Context::with_limits(Limits {
+            max_bytes: Some(solver_bytes * 2),
+            ..Limits::default()
+        });
+        ctx.new_solver(sudoku_grid).unwrap();
+        ctx.new_solver(sudoku_grid).unwrap();
+        match ctx.new_solver(sudoku_grid) {
+            Err(Error(ErrorKind::QuotaExceeded, _)) => {}
+            _ => panic!("expected QuotaExceeded"),
+        }
+
+        let usage = ctx.usage();
+        assert_eq!(usage.solvers, 2);
+        assert_eq!(usage.bytes, solver_bytes * 2);
     }
 }
@@ -1,5 +1,26 @@
 
 error_chain! {
+    errors {
+        Internal(message: String) {
+            description("internal error")
+            display("internal error: {}", message)
+        }
+        Overloaded {
+            description("too many pending requests")
+        }
+        InvalidArgument(message: String) {
+            description("invalid argument")
+            display("invalid argument: {}", message)
+        }
+        InvalidHandle(handle: u64) {
+            description("invalid handle")
+            display("invalid handle {}, it might have been destroyed already", handle)
+        }
+        BufferTooSmall(needed: usize) {
+            description("buffer is too small")
+            display("buffer is too small, {} bytes needed", needed)
+        }
+    }
     links {
         Context(::context::Error, ::context::ErrorKind);
     }
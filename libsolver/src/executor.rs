@@ -1,38 +1,152 @@
 //! Executor - actor-like processor of the incoming messages.
 
-use std::sync::mpsc::{channel, Sender};
-use std::thread;
-use context::Context;
+use std::cmp;
+use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
+use std::mem;
+use std::any::Any;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use context::{lock, Context, Event, Limits, SolverInfo, Usage};
+use journal::Recorder;
+use solver::{Assignment, Progress};
 use error::*;
 
-fn handle_req(req: Req, ctx: &mut Context) -> Resp {
-    fn handle(req_kind: ReqKind, ctx: &mut Context) -> Result<RespKind> {
-        let resp_kind = match req_kind {
-            ReqKind::CreateSolver { grid } => {
-                let id = ctx.new_solver(&grid)?;
+fn handle_req(
+    req: Req,
+    ctx: &Context,
+    interceptors: &[Box<Interceptor>],
+    progress: &mut FnMut(Progress),
+) -> Resp {
+    fn handle(
+        req_kind: &ReqKind,
+        ctx: &Context,
+        progress: &mut FnMut(Progress),
+    ) -> Result<RespKind> {
+        let resp_kind = match *req_kind {
+            ReqKind::CreateSolver { ref grid } => {
+                let id = ctx.new_solver(grid)?;
                 RespKind::SolverCreated { id }
             }
             ReqKind::Solve { id } => {
-                let solution = ctx.solve(id)?;
+                let solution = ctx.solve_with_progress(id, progress)?;
                 RespKind::SolverResult { solution }
             }
             ReqKind::Destroy { id } => {
                 ctx.destroy(id)?;
                 RespKind::Destroyed
             }
+            ReqKind::CloneSolver { id } => {
+                let id = ctx.clone_solver(id)?;
+                RespKind::SolverCloned { id }
+            }
+            ReqKind::SolveAssuming { id, ref assumptions } => {
+                let solution = ctx.solve_assuming(id, assumptions)?;
+                RespKind::SolverResult { solution }
+            }
+            ReqKind::SubscribeEvents => RespKind::EventsSubscribed,
+            ReqKind::Handshake { .. } => RespKind::Handshake {
+                version: PROTOCOL_VERSION,
+                features: FEATURES.iter().map(|feature| feature.to_string()).collect(),
+            },
+            ReqKind::GetUsage => {
+                let usage = ctx.usage();
+                let limits = ctx.limits();
+                RespKind::Usage { usage, limits }
+            }
+            ReqKind::ListSolvers => {
+                let ids = ctx.list();
+                RespKind::SolverList { ids }
+            }
+            ReqKind::DescribeSolver { id } => {
+                let info = ctx.describe(id)?;
+                RespKind::SolverInfo { info }
+            }
+            ReqKind::Batch {
+                ref kinds,
+                stop_on_error,
+            } => {
+                let total = kinds.len() as u32;
+                let mut results = Vec::with_capacity(kinds.len());
+                progress(Progress { done: 0, total });
+                for kind in kinds {
+                    let result = handle(kind, ctx, &mut |_| {});
+                    let is_err = result.is_err();
+                    results.push(result);
+                    progress(Progress {
+                        done: results.len() as u32,
+                        total,
+                    });
+                    if is_err && stop_on_error {
+                        break;
+                    }
+                }
+                RespKind::Batch { results }
+            }
         };
         Ok(resp_kind)
     }
 
-    let resp_kind = handle(req.kind, ctx);
-    Resp {
+    // Panic while handling a single request shouldn't take the whole
+    // executor down, so it is reported as an error instead.
+    let resp_kind = match panic::catch_unwind(AssertUnwindSafe(|| {
+        for interceptor in interceptors {
+            interceptor.before(&req)?;
+        }
+        handle(&req.kind, ctx, progress)
+    })) {
+        Ok(resp_kind) => resp_kind,
+        Err(payload) => Err(ErrorKind::Internal(panic_message(&*payload)).into()),
+    };
+    let mut resp = Resp {
         id: req.id,
         kind: resp_kind,
+    };
+
+    for interceptor in interceptors.iter().rev() {
+        let after = panic::catch_unwind(AssertUnwindSafe(|| interceptor.after(&req, &mut resp)));
+        if let Err(payload) = after {
+            resp.kind = Err(ErrorKind::Internal(panic_message(&*payload)).into());
+        }
+    }
+    resp
+}
+
+pub(crate) fn panic_message(payload: &(Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
     }
 }
 
+/// Receiver of responses and notifications of an `Executor`.
+///
+/// It is called by the workers, never concurrently. Responses to requests
+/// rejected due to overflow are passed on the thread that sent them, see
+/// `Config::overflow`.
 pub trait ExecutorCallback: Send {
     fn call(&mut self, r: Resp);
+
+    /// Called with lifecycle events of solvers, after the response to
+    /// the request that caused them.
+    fn event(&mut self, _event: Event) {}
+
+    /// Called when a worker has been restarted after a panic outside of
+    /// request handling, e.g. in this callback.
+    fn worker_restarted(&mut self, _message: &str) {}
+
+    /// Called with progress of the request with the given `id`, before
+    /// the response to it. Only for requests with `report_progress` set.
+    ///
+    /// Only batches are reported step by step, see `ReqKind::Batch`. Solving
+    /// is a single step, see `Solver::solve_with_progress`.
+    fn progress(&mut self, _id: usize, _progress: Progress) {}
 }
 
 impl<F: FnMut(Resp) + Send> ExecutorCallback for F {
@@ -41,39 +155,614 @@ impl<F: FnMut(Resp) + Send> ExecutorCallback for F {
     }
 }
 
+/// Hooks around handling of every request, e.g. for logging, metrics or
+/// access checks. See `Config::interceptors`.
+///
+/// Interceptors are shared by all workers, so they might be called
+/// concurrently. Panics in them are reported as `Internal` errors, like
+/// panics in request handling. Rejected requests, see `Executor::reject`
+/// and `Overflow`, bypass them.
+pub trait Interceptor: Send + Sync {
+    /// Called before the request is handled, in the order of
+    /// `Config::interceptors`. If an error is returned, the request isn't
+    /// handled and the rest of interceptors are skipped, the error is the
+    /// response instead.
+    ///
+    /// The request can't be altered, since the solvers it targets are
+    /// already reserved for it by the time it is handled.
+    fn before(&self, _req: &Req) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with every response before it is passed to the callback,
+    /// in the reverse order. This includes errors returned by `before`.
+    fn after(&self, _req: &Req, _resp: &mut Resp) {}
+}
+
+/// Configuration of an `Executor`.
+pub struct Config {
+    /// Limits of the context, owned by the executor.
+    pub limits: Limits,
+    /// Share parsed grids between solvers, see `Context::with_interning`.
+    pub intern_grids: bool,
+    /// Number of worker threads. Requests targeting the same solver are
+    /// processed in order, others might be processed concurrently.
+    pub workers: usize,
+    /// Maximum number of pending requests, `None` means unbounded. Requests
+    /// rejected with `Executor::reject` count as pending until responded to.
+    pub max_pending: Option<usize>,
+    /// What to do with a request when there are already `max_pending` of them.
+    ///
+    /// Unless it is `Overflow::Block`, the requests rejected due to overflow
+    /// are responded to synchronously, on the thread that called
+    /// `Executor::send`, so with `max_pending` set `send` must not be called
+    /// from the callback.
+    pub overflow: Overflow,
+    /// Interceptors of all requests, outermost first.
+    ///
+    /// Hosts using the C API or JNI can't pass them, so the transports
+    /// offer Rust constructors taking `Config` as well, e.g.
+    /// `capnproto::init_with_config` and `http::deploy`.
+    pub interceptors: Vec<Box<Interceptor>>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            limits: Limits::default(),
+            intern_grids: false,
+            workers: 1,
+            max_pending: None,
+            overflow: Overflow::Block,
+            interceptors: Vec::new(),
+        }
+    }
+}
+
+/// Policy for requests sent to a full queue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// Block `Executor::send` until there is space in the queue.
+    Block,
+    /// Respond to the new request with `Overloaded` error.
+    Reject,
+    /// Respond to the oldest pending request with `Overloaded` error
+    /// and enqueue the new one.
+    DropOldest,
+}
+
+/// Overflow policy codes of `ExecutorOptions`, see `Overflow`.
+pub const SOLVER_OVERFLOW_BLOCK: u32 = 0;
+pub const SOLVER_OVERFLOW_REJECT: u32 = 1;
+pub const SOLVER_OVERFLOW_DROP_OLDEST: u32 = 2;
+
+/// Configuration of an executor, as passed over FFI. See `Config`.
+///
+/// Zero means unlimited for all the limits.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ExecutorOptions {
+    pub workers: usize,
+    /// Maximum number of pending requests.
+    pub max_pending: usize,
+    /// One of `SOLVER_OVERFLOW_*` codes.
+    pub overflow: u32,
+    /// Quota on the approximate amount of memory used by solvers, in bytes.
+    pub max_bytes: usize,
+    pub max_solvers: usize,
+    /// Path of the file to record the journal into, see `journal::Recorder`,
+    /// or null. The string is only read during initialization.
+    pub journal_path: *const c_char,
+}
+
+impl ExecutorOptions {
+    /// `journal_path` as a string, `None` if it is null. Fails with
+    /// `InvalidArgument` if it isn't utf-8.
+    ///
+    /// # Safety
+    ///
+    /// `journal_path` must be null or point to a nul-terminated string,
+    /// which stays valid for `'a`. This can't be checked, so it is up to
+    /// the exported functions receiving the options to promise it.
+    pub unsafe fn journal_path<'a>(&self) -> Result<Option<&'a str>> {
+        if self.journal_path.is_null() {
+            return Ok(None);
+        }
+        let path = CStr::from_ptr(self.journal_path)
+            .to_str()
+            .map_err(|_| ErrorKind::InvalidArgument("`journal_path` isn't utf-8".to_string()))?;
+        Ok(Some(path))
+    }
+}
+
+impl Config {
+    /// Convert `options` received over FFI, with the journal recorded into
+    /// `journal_path`, see `ExecutorOptions::journal_path`. The raw pointer
+    /// in `options` is ignored. Fails with `InvalidArgument` if `options`
+    /// are malformed, or if the journal couldn't be created.
+    pub fn from_options(options: ExecutorOptions, journal_path: Option<&str>) -> Result<Config> {
+        fn non_zero(limit: usize) -> Option<usize> {
+            if limit == 0 {
+                None
+            } else {
+                Some(limit)
+            }
+        }
+
+        let overflow = match options.overflow {
+            SOLVER_OVERFLOW_BLOCK => Overflow::Block,
+            SOLVER_OVERFLOW_REJECT => Overflow::Reject,
+            SOLVER_OVERFLOW_DROP_OLDEST => Overflow::DropOldest,
+            code => bail!(ErrorKind::InvalidArgument(format!(
+                "unknown overflow policy {}",
+                code
+            ))),
+        };
+
+        let mut interceptors: Vec<Box<Interceptor>> = Vec::new();
+        if let Some(path) = journal_path {
+            let recorder = Recorder::create(path).map_err(|e| {
+                ErrorKind::InvalidArgument(format!("couldn't create the journal: {}", e))
+            })?;
+            interceptors.push(Box::new(recorder));
+        }
+
+        Ok(Config {
+            limits: Limits {
+                max_bytes: non_zero(options.max_bytes),
+                max_solvers: non_zero(options.max_solvers),
+            },
+            workers: options.workers,
+            max_pending: non_zero(options.max_pending),
+            overflow,
+            interceptors,
+            ..Config::default()
+        })
+    }
+}
+
+/// Requests waiting to be processed.
+struct Queue {
+    pending: VecDeque<Req>,
+    /// Requests that were rejected, see `Executor::reject`, and yet to be
+    /// responded to with the error.
+    rejected: VecDeque<(usize, Error)>,
+    /// Solvers targeted by the requests that are being processed at the moment.
+    in_flight: HashSet<usize>,
+    closed: bool,
+}
+
+impl Queue {
+    /// Take the request with the highest priority among those that can be
+    /// processed right now, i.e. that don't target a solver which is in
+    /// flight or targeted by an earlier pending request. Requests with equal
+    /// priorities are taken in order.
+    ///
+    /// Requests to the same solver are never reordered, even if a later
+    /// request has a higher priority.
+    fn take_ready(&mut self) -> Option<Req> {
+        let mut ready: Option<(usize, Priority)> = None;
+        {
+            let mut blocked = HashSet::new();
+            for (index, req) in self.pending.iter().enumerate() {
+                let targets = req.kind.targets();
+                let is_blocked = targets
+                    .iter()
+                    .any(|id| self.in_flight.contains(id) || blocked.contains(id));
+                blocked.extend(targets);
+                if is_blocked {
+                    continue;
+                }
+
+                let is_better = ready.map_or(true, |(_, priority)| req.priority > priority);
+                if is_better {
+                    ready = Some((index, req.priority));
+                }
+            }
+        }
+        let ready = ready.map(|(index, _)| index);
+
+        let req = match ready.and_then(|index| self.pending.remove(index)) {
+            Some(req) => req,
+            None => return None,
+        };
+        self.in_flight.extend(req.kind.targets());
+        Some(req)
+    }
+}
+
+struct Shared {
+    queue: Mutex<Queue>,
+    /// Signalled when a request might have become ready or the queue was closed.
+    ready: Condvar,
+    /// Signalled when a request was taken out of the queue or the queue was closed.
+    not_full: Condvar,
+    max_pending: Option<usize>,
+    overflow: Overflow,
+}
+
+/// Work item of a worker.
+enum Job {
+    Process(Req),
+    /// Respond to the request with this id with the error.
+    Reject(usize, Error),
+}
+
+thread_local! {
+    /// Events caused by the request being processed on this thread. Events
+    /// are emitted synchronously, so they are buffered per worker and passed
+    /// on right after the response to the request.
+    static EVENTS: RefCell<Vec<Event>> = RefCell::new(Vec::new());
+}
+
+/// State used by all workers of an executor.
+struct Worker<F> {
+    shared: Arc<Shared>,
+    ctx: Arc<Context>,
+    recv: Arc<Mutex<F>>,
+    interceptors: Arc<Vec<Box<Interceptor>>>,
+}
+
+/// Marks solvers as in flight until dropped.
+struct InFlight<'a> {
+    shared: &'a Shared,
+    ids: Vec<usize>,
+}
+
+impl<'a> Drop for InFlight<'a> {
+    fn drop(&mut self) {
+        if !self.ids.is_empty() {
+            let mut queue = lock(&self.shared.queue);
+            for id in &self.ids {
+                queue.in_flight.remove(id);
+            }
+            self.shared.ready.notify_all();
+        }
+    }
+}
+
+impl<F: ExecutorCallback> Worker<F> {
+    /// Process requests until shutdown, restarting after panics.
+    fn supervise(&self) {
+        loop {
+            match panic::catch_unwind(AssertUnwindSafe(|| self.run())) {
+                Ok(()) => return,
+                Err(payload) => {
+                    let message = panic_message(&*payload);
+                    lock(&self.recv).worker_restarted(&message);
+                }
+            }
+        }
+    }
+
+    fn run(&self) {
+        while let Some(job) = self.next_job() {
+            match job {
+                Job::Process(req) => self.process(req),
+                Job::Reject(id, error) => lock(&self.recv).call(Resp {
+                    id,
+                    kind: Err(error),
+                }),
+            }
+        }
+    }
+
+    fn process(&self, req: Req) {
+        let _in_flight = InFlight {
+            shared: &self.shared,
+            ids: req.kind.targets(),
+        };
+        let id = req.id;
+        let report_progress = req.report_progress;
+        let resp = {
+            let recv = &self.recv;
+            let mut progress = |progress| if report_progress {
+                lock(recv).progress(id, progress);
+            };
+            handle_req(req, &self.ctx, &self.interceptors, &mut progress)
+        };
+        let events = EVENTS.with(|events| mem::replace(&mut *events.borrow_mut(), Vec::new()));
+
+        let mut recv = lock(&self.recv);
+        recv.call(resp);
+        for event in events {
+            recv.event(event);
+        }
+    }
+
+    /// Wait for a job. Returns `None` once the queue is closed and
+    /// there is nothing left to do.
+    fn next_job(&self) -> Option<Job> {
+        let mut queue = lock(&self.shared.queue);
+        loop {
+            if let Some((id, error)) = queue.rejected.pop_front() {
+                self.shared.not_full.notify_one();
+                return Some(Job::Reject(id, error));
+            }
+            if let Some(req) = queue.take_ready() {
+                self.shared.not_full.notify_one();
+                return Some(Job::Process(req));
+            }
+            if queue.closed && queue.pending.is_empty() && queue.rejected.is_empty() {
+                return None;
+            }
+            queue = self.shared
+                .ready
+                .wait(queue)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+}
+
+/// How to deal with pending requests on shutdown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shutdown {
+    /// Process all pending requests before stopping.
+    Drain,
+    /// Drop pending requests without responding to them. Requests that
+    /// are already being processed are still completed.
+    Abort,
+}
+
 pub struct Executor {
-    sender: Sender<Req>,
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+    /// Passes responses to the callback. Released on shutdown.
+    respond: Option<Box<Fn(Resp) + Send + Sync>>,
 }
 
 impl Executor {
-    pub fn new<F: ExecutorCallback + 'static>(recv: F) -> Executor {
-        let (tx, rx) = channel();
-        let _ = thread::spawn(move || {
-            let mut recv = recv;
-            let mut ctx = Context::new();
+    pub fn with_config<F: ExecutorCallback + 'static>(config: Config, recv: F) -> Executor {
+        let ctx = if config.intern_grids {
+            Context::with_interning(config.limits)
+        } else {
+            Context::with_limits(config.limits)
+        };
 
-            for req in rx {
-                let resp = handle_req(req, &mut ctx);
-                recv.call(resp);
-            }
+        // The context is used only by the workers.
+        ctx.subscribe(|event| EVENTS.with(|events| events.borrow_mut().push(event)));
+
+        let shared = Arc::new(Shared {
+            queue: Mutex::new(Queue {
+                pending: VecDeque::new(),
+                rejected: VecDeque::new(),
+                in_flight: HashSet::new(),
+                closed: false,
+            }),
+            ready: Condvar::new(),
+            not_full: Condvar::new(),
+            max_pending: config.max_pending,
+            overflow: config.overflow,
         });
-        Executor { sender: tx }
+        let ctx = Arc::new(ctx);
+        let recv = Arc::new(Mutex::new(recv));
+        let interceptors = Arc::new(config.interceptors);
+        let respond: Box<Fn(Resp) + Send + Sync> = {
+            let recv = Arc::clone(&recv);
+            Box::new(move |resp| lock(&recv).call(resp))
+        };
+
+        let workers = (0..cmp::max(config.workers, 1))
+            .map(|_| {
+                let worker = Worker {
+                    shared: Arc::clone(&shared),
+                    ctx: Arc::clone(&ctx),
+                    recv: Arc::clone(&recv),
+                    interceptors: Arc::clone(&interceptors),
+                };
+                thread::spawn(move || worker.supervise())
+            })
+            .collect();
+
+        Executor {
+            shared,
+            workers,
+            respond: Some(respond),
+        }
     }
 
+    /// Enqueue `req`. Requests sent after shutdown are ignored.
+    ///
+    /// If the queue is full, `req` is handled according to `Config::overflow`.
+    /// With `max_pending` set this must not be called from the callback.
     pub fn send(&self, req: Req) {
-        self.sender.send(req).unwrap();
+        let overloaded = {
+            let (mut queue, is_full) = match self.wait_for_space() {
+                Some(space) => space,
+                None => return,
+            };
+            let drop_oldest = self.shared.overflow == Overflow::DropOldest;
+            let overloaded = if !is_full {
+                None
+            } else if drop_oldest && !queue.pending.is_empty() {
+                queue.pending.pop_front().map(|oldest| oldest.id)
+            } else {
+                Some(req.id)
+            };
+            if !is_full || drop_oldest {
+                queue.pending.push_back(req);
+                self.shared.ready.notify_one();
+            }
+            overloaded
+        };
+
+        // The queue is unlocked, so that workers aren't held up by the callback.
+        if let Some(id) = overloaded {
+            self.respond(Resp {
+                id,
+                kind: Err(ErrorKind::Overloaded.into()),
+            });
+        }
+    }
+
+    /// Respond to the request `id` with `error` without processing it, e.g.
+    /// when the request couldn't be decoded. The response is passed to the
+    /// callback by a worker, like responses to the other requests, and
+    /// bypasses interceptors. Ignored after shutdown.
+    ///
+    /// If the queue is full, this waits for space with `Overflow::Block`,
+    /// otherwise responds synchronously, see `Config::overflow`.
+    pub fn reject(&self, id: usize, error: Error) {
+        let (mut queue, is_full) = match self.wait_for_space() {
+            Some(space) => space,
+            None => return,
+        };
+        if is_full {
+            drop(queue);
+            self.respond(Resp {
+                id,
+                kind: Err(error),
+            });
+        } else {
+            queue.rejected.push_back((id, error));
+            self.shared.ready.notify_one();
+        }
+    }
+
+    /// Lock the queue, waiting for space in it with `Overflow::Block`.
+    /// Returns whether it is still full, or `None` after shutdown.
+    fn wait_for_space(&self) -> Option<(MutexGuard<Queue>, bool)> {
+        let mut queue = lock(&self.shared.queue);
+        loop {
+            if queue.closed {
+                return None;
+            }
+            let len = queue.pending.len() + queue.rejected.len();
+            let is_full = self.shared
+                .max_pending
+                .map_or(false, |max_pending| len >= max_pending);
+            if !is_full || self.shared.overflow != Overflow::Block {
+                return Some((queue, is_full));
+            }
+            queue = self.shared
+                .not_full
+                .wait(queue)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Pass `resp` to the callback on this thread.
+    fn respond(&self, resp: Resp) {
+        if let Some(ref respond) = self.respond {
+            respond(resp);
+        }
+    }
+
+    /// Stop the workers and wait for them to exit. The context and the
+    /// callback are released once this returns, and the callback is never
+    /// called afterwards.
+    ///
+    /// Must not be called from the callback, since that would deadlock.
+    /// Subsequent calls do nothing.
+    pub fn shutdown(&mut self, mode: Shutdown) {
+        {
+            let mut queue = lock(&self.shared.queue);
+            queue.closed = true;
+            if mode == Shutdown::Abort {
+                queue.pending.clear();
+                queue.rejected.clear();
+            }
+        }
+        self.shared.ready.notify_all();
+        self.shared.not_full.notify_all();
+
+        for worker in self.workers.drain(..) {
+            // Workers recover from panics, see `Worker::supervise`.
+            let _ = worker.join();
+        }
+        self.respond = None;
+    }
+}
+
+impl Drop for Executor {
+    fn drop(&mut self) {
+        self.shutdown(Shutdown::Drain);
+    }
+}
+
+/// Version of the protocol spoken by executors, reported in response to
+/// `ReqKind::Handshake`. Bumped on changes older clients can't cope with.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Optional features supported by executors, reported in response to
+/// `ReqKind::Handshake`. Names are never reused for something else.
+pub const FEATURES: &'static [&'static str] = &["batch", "events", "priority", "progress"];
+
+/// Priority of a request. Among requests that are ready to be processed
+/// the one with the highest priority is picked first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    Low,
+    Normal,
+    High,
+}
+
+impl Default for Priority {
+    fn default() -> Priority {
+        Priority::Normal
     }
 }
 
 pub struct Req {
     pub id: usize,
     pub kind: ReqKind,
+    pub priority: Priority,
+    /// Whether to report progress of the request, see `ExecutorCallback::progress`.
+    pub report_progress: bool,
 }
 
 pub enum ReqKind {
     CreateSolver { grid: String },
     Solve { id: usize },
     Destroy { id: usize },
+    ListSolvers,
+    DescribeSolver { id: usize },
+    CloneSolver { id: usize },
+    SolveAssuming {
+        id: usize,
+        assumptions: Vec<Assignment>,
+    },
+    GetUsage,
+    /// Tells the transport that the client is ready to receive events.
+    ///
+    /// Events are always passed to `ExecutorCallback::event`, it is up to the
+    /// transport whether to forward them before the client subscribed.
+    SubscribeEvents,
+    /// Several requests processed one after another, responded with
+    /// `RespKind::Batch`. If `stop_on_error` is set, the requests after
+    /// the first failed one are skipped.
+    ///
+    /// Progress is reported as the number of processed requests.
+    Batch {
+        kinds: Vec<ReqKind>,
+        stop_on_error: bool,
+    },
+    /// Exchange of protocol versions and supported features, responded with
+    /// `RespKind::Handshake`. The client's ones are informational, e.g. for
+    /// interceptors, the response doesn't depend on them.
+    Handshake {
+        version: u32,
+        features: Vec<String>,
+    },
+}
+
+impl ReqKind {
+    /// Ids of the solvers this request operates on.
+    fn targets(&self) -> Vec<usize> {
+        match *self {
+            ReqKind::Solve { id } |
+            ReqKind::Destroy { id } |
+            ReqKind::DescribeSolver { id } |
+            ReqKind::CloneSolver { id } |
+            ReqKind::SolveAssuming { id, .. } => vec![id],
+            ReqKind::Batch { ref kinds, .. } => kinds.iter().flat_map(ReqKind::targets).collect(),
+            ReqKind::CreateSolver { .. } |
+            ReqKind::ListSolvers |
+            ReqKind::GetUsage |
+            ReqKind::SubscribeEvents |
+            ReqKind::Handshake { .. } => vec![],
+        }
+    }
 }
 
 pub struct Resp {
@@ -85,4 +774,472 @@ pub enum RespKind {
     SolverCreated { id: usize },
     SolverResult { solution: String },
     Destroyed,
+    SolverList { ids: Vec<usize> },
+    SolverInfo { info: SolverInfo },
+    SolverCloned { id: usize },
+    Usage { usage: Usage, limits: Limits },
+    EventsSubscribed,
+    /// Results of the batched requests in order. Contains fewer results than
+    /// there were requests if the batch has been stopped on error.
+    Batch { results: Vec<Result<RespKind>> },
+    /// Protocol version of the executor and the features it supports.
+    Handshake { version: u32, features: Vec<String> },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::{channel, Receiver, Sender};
+
+    fn req(id: usize, kind: ReqKind) -> Req {
+        Req {
+            id,
+            kind,
+            priority: Priority::Normal,
+            report_progress: false,
+        }
+    }
+
+    #[test]
+    fn queue_keeps_order_per_solver() {
+        let mut queue = Queue {
+            pending: VecDeque::new(),
+            rejected: VecDeque::new(),
+            in_flight: HashSet::new(),
+            closed: false,
+        };
+        queue.pending.push_back(req(0, ReqKind::Solve { id: 1 }));
+        queue.pending.push_back(req(1, ReqKind::Destroy { id: 1 }));
+        queue.pending.push_back(req(2, ReqKind::Solve { id: 2 }));
+        queue.pending.push_back(req(3, ReqKind::ListSolvers));
+
+        assert_eq!(queue.take_ready().unwrap().id, 0);
+        // Solver 1 is in flight, so the destroy request has to wait.
+        assert_eq!(queue.take_ready().unwrap().id, 2);
+        assert_eq!(queue.take_ready().unwrap().id, 3);
+        assert!(queue.take_ready().is_none());
+
+        queue.in_flight.remove(&1);
+        assert_eq!(queue.take_ready().unwrap().id, 1);
+    }
+
+    #[test]
+    fn queue_prefers_higher_priority() {
+        let mut queue = Queue {
+            pending: VecDeque::new(),
+            rejected: VecDeque::new(),
+            in_flight: HashSet::new(),
+            closed: false,
+        };
+        let with_priority = |id, kind, priority| Req { priority, ..req(id, kind) };
+        queue.pending.push_back(with_priority(0, ReqKind::Solve { id: 1 }, Priority::Low));
+        queue.pending.push_back(with_priority(1, ReqKind::ListSolvers, Priority::Normal));
+        queue.pending.push_back(with_priority(2, ReqKind::Destroy { id: 1 }, Priority::High));
+        queue.pending.push_back(with_priority(3, ReqKind::GetUsage, Priority::High));
+        queue.pending.push_back(with_priority(4, ReqKind::ListSolvers, Priority::High));
+
+        // Destroy of solver 1 has to wait for the earlier solve.
+        assert_eq!(queue.take_ready().unwrap().id, 3);
+        assert_eq!(queue.take_ready().unwrap().id, 4);
+        assert_eq!(queue.take_ready().unwrap().id, 1);
+        assert_eq!(queue.take_ready().unwrap().id, 0);
+        assert!(queue.take_ready().is_none());
+
+        queue.in_flight.remove(&1);
+        assert_eq!(queue.take_ready().unwrap().id, 2);
+    }
+
+    #[test]
+    fn progress() {
+        struct ProgressCallback(Sender<(usize, Option<Progress>)>);
+
+        impl ExecutorCallback for ProgressCallback {
+            fn call(&mut self, resp: Resp) {
+                self.0.send((resp.id, None)).unwrap();
+            }
+
+            fn progress(&mut self, id: usize, progress: Progress) {
+                self.0.send((id, Some(progress))).unwrap();
+            }
+        }
+
+        let sudoku_grid = include_str!("sudoku.txt");
+        let (tx, rx) = channel();
+        let executor = Executor::with_config(Config::default(), ProgressCallback(tx));
+        executor.send(req(0, ReqKind::CreateSolver { grid: sudoku_grid.to_string() }));
+        executor.send(req(1, ReqKind::Solve { id: 0 }));
+        executor.send(Req {
+            report_progress: true,
+            ..req(2, ReqKind::Solve { id: 0 })
+        });
+
+        let messages: Vec<_> = rx.iter().take(5).collect();
+        assert_eq!(
+            messages,
+            vec![
+                (0, None),
+                (1, None),
+                (2, Some(Progress { done: 0, total: 1 })),
+                (2, Some(Progress { done: 1, total: 1 })),
+                (2, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn queue_batch_targets() {
+        let mut queue = Queue {
+            pending: VecDeque::new(),
+            rejected: VecDeque::new(),
+            in_flight: HashSet::new(),
+            closed: false,
+        };
+        let batch = ReqKind::Batch {
+            kinds: vec![ReqKind::Solve { id: 1 }, ReqKind::Solve { id: 2 }],
+            stop_on_error: false,
+        };
+        queue.pending.push_back(req(0, ReqKind::Solve { id: 2 }));
+        queue.pending.push_back(req(1, batch));
+        queue.pending.push_back(req(2, ReqKind::Solve { id: 1 }));
+
+        assert_eq!(queue.take_ready().unwrap().id, 0);
+        // The batch waits for solver 2, and the request to solver 1 waits for the batch.
+        assert!(queue.take_ready().is_none());
+
+        queue.in_flight.remove(&2);
+        assert_eq!(queue.take_ready().unwrap().id, 1);
+        assert!(queue.take_ready().is_none());
+    }
+
+    #[test]
+    fn batch() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let ctx = Context::new();
+        let batch = |stop_on_error| ReqKind::Batch {
+            kinds: vec![
+                ReqKind::CreateSolver { grid: sudoku_grid.to_string() },
+                ReqKind::Solve { id: 42 },
+                ReqKind::Solve { id: 0 },
+            ],
+            stop_on_error,
+        };
+        let unwrap_results = |resp: Resp| match resp.kind {
+            Ok(RespKind::Batch { results }) => results,
+            _ => panic!("expected batch"),
+        };
+
+        let mut progress = Vec::new();
+        let resp = handle_req(req(0, batch(false)), &ctx, &[], &mut |p| progress.push(p));
+        let results = unwrap_results(resp);
+        assert_eq!(results.len(), 3);
+        match (&results[0], &results[1], &results[2]) {
+            (&Ok(RespKind::SolverCreated { id: 0 }), &Err(_), &Ok(RespKind::SolverResult { .. })) => {}
+            _ => panic!("unexpected results"),
+        }
+        assert_eq!(progress.len(), 4);
+        assert_eq!(progress[3], Progress { done: 3, total: 3 });
+
+        let resp = handle_req(req(1, batch(true)), &ctx, &[], &mut |_| {});
+        assert_eq!(unwrap_results(resp).len(), 2);
+    }
+
+    #[test]
+    fn interceptors() {
+        struct Deny(usize);
+
+        impl Interceptor for Deny {
+            fn before(&self, req: &Req) -> Result<()> {
+                if req.kind.targets().contains(&self.0) {
+                    bail!(ErrorKind::Internal("access denied".to_string()));
+                }
+                Ok(())
+            }
+        }
+
+        struct Log(Arc<Mutex<Vec<(usize, bool)>>>);
+
+        impl Interceptor for Log {
+            fn after(&self, req: &Req, resp: &mut Resp) {
+                lock(&self.0).push((req.id, resp.kind.is_ok()));
+            }
+        }
+
+        struct Panic;
+
+        impl Interceptor for Panic {
+            fn after(&self, req: &Req, _resp: &mut Resp) {
+                if req.id == 3 {
+                    panic!("oops");
+                }
+            }
+        }
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (tx, rx) = channel();
+        let executor = Executor::with_config(
+            Config {
+                interceptors: vec![
+                    Box::new(Log(Arc::clone(&log))),
+                    Box::new(Deny(1)),
+                    Box::new(Panic),
+                ],
+                ..Config::default()
+            },
+            move |resp| tx.send(resp).unwrap(),
+        );
+        executor.send(req(0, ReqKind::ListSolvers));
+        executor.send(req(1, ReqKind::Destroy { id: 1 }));
+        executor.send(req(2, ReqKind::Destroy { id: 2 }));
+        executor.send(req(3, ReqKind::ListSolvers));
+
+        let resps: Vec<Resp> = rx.iter().take(4).collect();
+        match resps[1].kind {
+            Err(Error(ErrorKind::Internal(ref message), _)) => assert_eq!(message, "access denied"),
+            _ => panic!("expected denied request"),
+        }
+        match resps[2].kind {
+            Err(Error(ErrorKind::Context(_), _)) => {}
+            _ => panic!("expected context error"),
+        }
+        match resps[3].kind {
+            Err(Error(ErrorKind::Internal(ref message), _)) => assert_eq!(message, "oops"),
+            _ => panic!("expected panic to be reported"),
+        }
+        assert_eq!(
+            *lock(&log),
+            vec![(0, true), (1, false), (2, false), (3, false)]
+        );
+    }
+
+    #[test]
+    fn shutdown() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let sudoku_grid = include_str!("sudoku.txt");
+        for &mode in &[Shutdown::Drain, Shutdown::Abort] {
+            let calls = Arc::new(AtomicUsize::new(0));
+            let mut executor = {
+                let calls = Arc::clone(&calls);
+                Executor::with_config(Config::default(), move |_| {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                })
+            };
+
+            for msg_id in 0..16 {
+                executor.send(req(msg_id, ReqKind::CreateSolver { grid: sudoku_grid.to_string() }));
+            }
+            executor.shutdown(mode);
+
+            let calls_after_shutdown = calls.load(Ordering::SeqCst);
+            if mode == Shutdown::Drain {
+                assert_eq!(calls_after_shutdown, 16);
+            }
+
+            // Callback isn't called after shutdown, even for new requests.
+            executor.send(req(16, ReqKind::ListSolvers));
+            thread::sleep(::std::time::Duration::from_millis(10));
+            assert_eq!(calls.load(Ordering::SeqCst), calls_after_shutdown);
+
+            // The callback was released along with the workers.
+            assert_eq!(Arc::strong_count(&calls), 1);
+        }
+    }
+
+    #[test]
+    fn worker_restart() {
+        struct PanickyCallback {
+            resps: Sender<usize>,
+            restarts: Sender<String>,
+        }
+
+        impl ExecutorCallback for PanickyCallback {
+            fn call(&mut self, resp: Resp) {
+                if resp.id == 0 {
+                    panic!("callback panicked");
+                }
+                self.resps.send(resp.id).unwrap();
+            }
+
+            fn worker_restarted(&mut self, message: &str) {
+                self.restarts.send(message.to_string()).unwrap();
+            }
+        }
+
+        let (resps_tx, resps_rx) = channel();
+        let (restarts_tx, restarts_rx) = channel();
+        let executor = Executor::with_config(Config::default(), PanickyCallback {
+            resps: resps_tx,
+            restarts: restarts_tx,
+        });
+        executor.send(req(0, ReqKind::Solve { id: 0 }));
+        executor.send(req(1, ReqKind::Solve { id: 0 }));
+
+        assert_eq!(restarts_rx.recv().unwrap(), "callback panicked");
+        // Solver 0 is no longer in flight, so the next request goes through.
+        assert_eq!(resps_rx.recv().unwrap(), 1);
+    }
+
+    #[test]
+    fn panic_message() {
+        let payload = panic::catch_unwind(|| panic!("oops {}", 1)).unwrap_err();
+        assert_eq!(super::panic_message(&*payload), "oops 1");
+    }
+
+    /// Run `max_pending + 2` list requests through an executor, whose only
+    /// worker is busy with another request, and collect responses.
+    fn overflow(overflow: Overflow) -> Vec<(usize, bool)> {
+        struct Hold {
+            started: Mutex<Sender<()>>,
+            release: Mutex<Receiver<()>>,
+        }
+        impl Interceptor for Hold {
+            fn before(&self, req: &Req) -> Result<()> {
+                if req.id == 0 {
+                    lock(&self.started).send(()).unwrap();
+                    lock(&self.release).recv().unwrap();
+                }
+                Ok(())
+            }
+        }
+
+        let (started_tx, started_rx) = channel();
+        let (release_tx, release_rx) = channel();
+        let (resps_tx, resps_rx) = channel();
+        let executor = Executor::with_config(
+            Config {
+                max_pending: Some(1),
+                overflow,
+                interceptors: vec![
+                    Box::new(Hold {
+                        started: Mutex::new(started_tx),
+                        release: Mutex::new(release_rx),
+                    }),
+                ],
+                ..Config::default()
+            },
+            move |resp: Resp| resps_tx.send((resp.id, resp.kind.is_ok())).unwrap(),
+        );
+
+        executor.send(req(0, ReqKind::ListSolvers));
+        started_rx.recv().unwrap();
+        executor.send(req(1, ReqKind::ListSolvers));
+        executor.send(req(2, ReqKind::ListSolvers));
+        release_tx.send(()).unwrap();
+
+        resps_rx.iter().take(3).collect()
+    }
+
+    #[test]
+    fn overflow_reject() {
+        assert_eq!(overflow(Overflow::Reject), vec![(2, false), (0, true), (1, true)]);
+    }
+
+    #[test]
+    fn overflow_drop_oldest() {
+        assert_eq!(overflow(Overflow::DropOldest), vec![(1, false), (0, true), (2, true)]);
+    }
+
+    #[test]
+    fn options() {
+        let options = ExecutorOptions {
+            workers: 2,
+            max_pending: 0,
+            overflow: SOLVER_OVERFLOW_DROP_OLDEST,
+            max_bytes: 0,
+            max_solvers: 3,
+            journal_path: ::std::ptr::null(),
+        };
+        let config = Config::from_options(options, None).unwrap();
+        assert_eq!(config.overflow, Overflow::DropOldest);
+        assert_eq!(config.max_pending, None);
+        assert_eq!(config.limits.max_solvers, Some(3));
+
+        match Config::from_options(ExecutorOptions { overflow: 3, ..options }, None) {
+            Err(Error(ErrorKind::InvalidArgument(_), _)) => {}
+            _ => panic!("expected InvalidArgument"),
+        }
+    }
+
+    #[test]
+    fn events_follow_responses() {
+        enum Message {
+            Resp(usize),
+            Event(Event),
+        }
+
+        struct EventsCallback(Sender<Message>);
+
+        impl ExecutorCallback for EventsCallback {
+            fn call(&mut self, resp: Resp) {
+                if let Ok(RespKind::SolverCreated { id }) = resp.kind {
+                    self.0.send(Message::Resp(id)).unwrap();
+                }
+            }
+
+            fn event(&mut self, event: Event) {
+                self.0.send(Message::Event(event)).unwrap();
+            }
+        }
+
+        let sudoku_grid = include_str!("sudoku.txt");
+        let (tx, rx) = channel();
+        let executor = Executor::with_config(
+            Config {
+                workers: 4,
+                ..Config::default()
+            },
+            EventsCallback(tx),
+        );
+        for msg_id in 0..32 {
+            executor.send(req(msg_id, ReqKind::CreateSolver { grid: sudoku_grid.to_string() }));
+        }
+
+        // Every event comes right after the response to the request that caused it.
+        let messages: Vec<Message> = rx.iter().take(64).collect();
+        for pair in messages.chunks(2) {
+            match (&pair[0], &pair[1]) {
+                (&Message::Resp(id), &Message::Event(event)) => assert_eq!(event.id, id),
+                _ => panic!("event doesn't follow its response"),
+            }
+        }
+    }
+
+    #[test]
+    fn pooled_executor() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let (tx, rx) = channel();
+        let executor = Executor::with_config(
+            Config {
+                workers: 4,
+                ..Config::default()
+            },
+            move |resp| tx.send(resp).unwrap(),
+        );
+
+        for msg_id in 0..8 {
+            executor.send(req(msg_id, ReqKind::CreateSolver { grid: sudoku_grid.to_string() }));
+        }
+        let mut ids: Vec<usize> = rx.iter()
+            .take(8)
+            .map(|resp| match resp.kind {
+                Ok(RespKind::SolverCreated { id }) => id,
+                _ => panic!("expected SolverCreated"),
+            })
+            .collect();
+        ids.sort();
+        assert_eq!(ids, (0..8).collect::<Vec<_>>());
+
+        // Solve and destroy of the same solver must not be reordered.
+        for (msg_id, &id) in ids.iter().enumerate() {
+            executor.send(req(msg_id * 2, ReqKind::Solve { id }));
+            executor.send(req(msg_id * 2 + 1, ReqKind::Destroy { id }));
+        }
+        for resp in rx.iter().take(16) {
+            match resp.kind {
+                Ok(RespKind::SolverResult { .. }) => assert_eq!(resp.id % 2, 0),
+                Ok(RespKind::Destroyed) => assert_eq!(resp.id % 2, 1),
+                _ => panic!("unexpected response"),
+            }
+        }
+    }
 }
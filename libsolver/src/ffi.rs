@@ -1,52 +1,712 @@
-use std::os::raw::c_char;
+//! C API of the solvers.
+//!
+//! All functions can be called from any thread. Calls on distinct solvers
+//! run concurrently, while calls on the same solver are serialized by a
+//! lock of that solver. Callbacks are called on the calling thread before
+//! the function returns, and may call back into the library, except for
+//! the `progress` callback using the solver being solved. A solver can be
+//! destroyed while it is being used by another thread, the call in
+//! progress completes and subsequent ones fail with `SOLVER_INVALID_HANDLE`.
+//!
+//! Declarations of the whole C API, including `capnproto` and `http`, are
+//! in `include/turbosolver.h`, generated with cbindgen.
+
+use std::os::raw::{c_char, c_void};
+use std::cell::RefCell;
 use std::ffi::{CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
 use std::ptr;
+use std::sync::{Arc, Mutex};
 
+use context::{self, lock};
+use executor::panic_message;
+use handles::HandleTable;
 use solver::*;
 use error::*;
 
+/// Status codes returned by the exported functions. On failure the
+/// description of the error is available via `solver_last_error_message`.
+pub const SOLVER_OK: u32 = 0;
+/// The operation has failed, e.g. the grid couldn't be parsed.
+pub const SOLVER_ERROR: u32 = 1;
+/// An argument is invalid, e.g. a null pointer.
+pub const SOLVER_INVALID_ARGUMENT: u32 = 2;
+/// The library has panicked, which is a bug. Panics never cross the FFI
+/// boundary, but objects involved might be left in an unusable state.
+pub const SOLVER_PANIC: u32 = 3;
+/// There is no solution, returned by functions without a callback.
+pub const SOLVER_NOT_FOUND: u32 = 4;
+/// The buffer passed by the caller is too small for the result.
+pub const SOLVER_BUFFER_TOO_SMALL: u32 = 5;
+/// The solver handle is invalid, e.g. it has been destroyed already.
+pub const SOLVER_INVALID_HANDLE: u32 = 6;
+
+/// Version of the C API, bumped on changes existing callers can't cope
+/// with. Additions are discovered with `turbosolver_has_feature` instead.
+pub const TURBOSOLVER_ABI_VERSION: u32 = 2;
+
+/// Optional parts of the C API, see `turbosolver_has_feature`. Names are
+/// never reused for something else.
+const FEATURES: &'static [&'static str] = &[
+    "buffers",
+    "capnp",
+    "events",
+    "handles",
+    "http",
+    "journal",
+    "last_error",
+    "progress",
+    "user_data",
+];
+
+/// Features which are only available on some platforms.
+#[cfg(unix)]
+const PLATFORM_FEATURES: &'static [&'static str] = &["rpc"];
+#[cfg(not(unix))]
+const PLATFORM_FEATURES: &'static [&'static str] = &[];
+
+/// Version of the C API of the loaded library. Hosts should check it
+/// against `TURBOSOLVER_ABI_VERSION` of the header they were built with.
 #[no_mangle]
-pub extern "C" fn solver_create(sudoku_grid: *const c_char, f: fn(*mut Solver, *const c_char)) {
-    assert!(!sudoku_grid.is_null(), "sudoku_grid should not be null");
+pub extern "C" fn turbosolver_abi_version() -> u32 {
+    TURBOSOLVER_ABI_VERSION
+}
 
-    fn solver_create_inner(sudoku_grid: *const c_char) -> Result<Box<Solver>> {
-        unsafe {
-            let cstr = CStr::from_ptr(sudoku_grid).to_str()?;
-            let solver = Solver::from_str(cstr)?;
-            Ok(Box::new(solver))
+/// Whether the loaded library supports the optional `feature`, e.g.
+/// `"buffers"` for `solver_solve_into` and friends. Unknown features and
+/// null are reported as unsupported.
+#[no_mangle]
+pub extern "C" fn turbosolver_has_feature(feature: *const c_char) -> bool {
+    catch(|| {
+        non_null(feature, "feature")?;
+        let feature = unsafe { CStr::from_ptr(feature) }.to_bytes();
+        Ok(FEATURES
+            .iter()
+            .chain(PLATFORM_FEATURES)
+            .any(|known| known.as_bytes() == feature))
+    }).unwrap_or(false)
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+fn set_last_error(message: String) {
+    // Messages are passed as C strings, so nul bytes can't be kept.
+    let message = CString::new(message.replace('\0', "")).expect("nul bytes are removed");
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+}
+
+/// Description of the last error on the calling thread, empty if none.
+/// Used by the JNI bindings, which throw it as an exception.
+#[cfg(target_os = "android")]
+pub(crate) fn last_error_message() -> String {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(String::new(), |message| message.to_string_lossy().into_owned())
+    })
+}
+
+/// Run `f` on behalf of an exported function, catching panics, so they
+/// don't unwind into the caller. On failure the error is stored as the
+/// last error of this thread and its status code is returned.
+pub(crate) fn catch<T, F: FnOnce() -> Result<T>>(f: F) -> ::std::result::Result<T, u32> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => {
+            let status = match *e.kind() {
+                ErrorKind::InvalidArgument(_) => SOLVER_INVALID_ARGUMENT,
+                ErrorKind::Context(context::ErrorKind::SolutionNotFound) => SOLVER_NOT_FOUND,
+                ErrorKind::BufferTooSmall(_) => SOLVER_BUFFER_TOO_SMALL,
+                ErrorKind::InvalidHandle(_) => SOLVER_INVALID_HANDLE,
+                _ => SOLVER_ERROR,
+            };
+            set_last_error(e.to_string());
+            Err(status)
         }
+        Err(payload) => {
+            set_last_error(format!("panic: {}", panic_message(&*payload)));
+            Err(SOLVER_PANIC)
+        }
+    }
+}
+
+/// Status code of the outcome of `catch`.
+pub(crate) fn status(result: ::std::result::Result<(), u32>) -> u32 {
+    result.err().unwrap_or(SOLVER_OK)
+}
+
+/// Fail with `InvalidArgument` if `ptr` is null.
+pub(crate) fn non_null<T>(ptr: *const T, name: &str) -> Result<()> {
+    if ptr.is_null() {
+        bail!(ErrorKind::InvalidArgument(format!("`{}` is null", name)));
     }
+    Ok(())
+}
 
-    match solver_create_inner(sudoku_grid) {
-        Ok(solver) => f(Box::into_raw(solver), ptr::null()),
-        Err(e) => {
-            // Mind that `c_err_str` must outlive `f` call!
-            let c_err_str =
-                CString::new(e.description()).expect("e.description() should be valid cstring");
-            f(ptr::null_mut(), c_err_str.as_ptr());
-        }
+/// Description of the last error on the calling thread, or null if
+/// nothing has failed on it yet.
+///
+/// Only meaningful right after a function returned a status other than
+/// `SOLVER_OK`, or null where a pointer was expected, since successful
+/// calls don't reset it. The string is valid until the next failure on
+/// the same thread.
+#[no_mangle]
+pub extern "C" fn solver_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Handle of a solver, `0` is never a valid one. Handles of destroyed
+/// solvers are detected and rejected with `SOLVER_INVALID_HANDLE`, even
+/// if a new solver has been created since.
+pub type SolverHandle = u64;
+
+lazy_static! {
+    static ref SOLVERS: Mutex<HandleTable<Arc<Mutex<Solver>>>> = Mutex::new(HandleTable::new());
+}
+
+/// Call `f` with the solver behind `handle`, holding the lock of the
+/// solver only. `f` must not use the same solver.
+fn with_solver<R, F: FnOnce(&mut Solver) -> R>(handle: SolverHandle, f: F) -> Result<R> {
+    // Don't hold the table while `f` runs, so other solvers aren't blocked.
+    let solver = match lock(&SOLVERS).get(handle) {
+        Some(solver) => Arc::clone(solver),
+        None => bail!(ErrorKind::InvalidHandle(handle)),
+    };
+    let mut solver = lock(&solver);
+    Ok(f(&mut solver))
+}
+
+/// Lifecycle event codes passed to `EventCallback`.
+///
+/// Solvers aren't evicted, they only go away with `solver_destroy`.
+pub const SOLVER_EVENT_CREATED: u32 = 0;
+pub const SOLVER_EVENT_SOLVED: u32 = 1;
+pub const SOLVER_EVENT_DESTROYED: u32 = 2;
+
+/// Callback that is called on lifecycle events of all solvers.
+///
+/// It is called on the thread that caused the event. On `SOLVER_EVENT_DESTROYED`
+/// the handle is already invalid.
+pub type EventCallback = extern "C" fn(event: u32, solver: SolverHandle);
+
+lazy_static! {
+    static ref EVENT_CALLBACK: Mutex<Option<EventCallback>> = Mutex::new(None);
+}
+
+fn emit(event: u32, solver: SolverHandle) {
+    // Copy the callback out, so it can call `solver_set_event_callback` itself.
+    let callback = *lock(&EVENT_CALLBACK);
+    if let Some(callback) = callback {
+        callback(event, solver);
     }
 }
 
+/// Register `callback` to be called on lifecycle events, replacing the previous one.
+/// Pass null to unregister.
 #[no_mangle]
-pub extern "C" fn solver_solve(solver: *mut Solver, f: fn(*const c_char)) {
-    unsafe {
-        let solver = solver.as_mut().expect("solver should not be null");
-        match solver.solve() {
+pub extern "C" fn solver_set_event_callback(callback: Option<EventCallback>) -> u32 {
+    status(catch(|| {
+        *lock(&EVENT_CALLBACK) = callback;
+        Ok(())
+    }))
+}
+
+/// Callback that is called with the new solver, or with the description
+/// of the error if it couldn't be created.
+pub type CreateCallback = extern "C" fn(solver: SolverHandle, error: *const c_char);
+
+/// Same as `CreateCallback`, with the `user_data` passed to `solver_create_with_data`.
+pub type CreateCallbackWithData =
+    extern "C" fn(user_data: *mut c_void, solver: SolverHandle, error: *const c_char);
+
+/// Callback that is called with the solution, or null if there is none.
+pub type SolveCallback = extern "C" fn(solution: *const c_char);
+
+/// Same as `SolveCallback`, with the `user_data` passed to `solver_solve_with_data`.
+pub type SolveCallbackWithData = extern "C" fn(user_data: *mut c_void, solution: *const c_char);
+
+/// Callback that is called with progress of a long-running operation:
+/// `done` out of `total` steps.
+pub type ProgressCallback = extern "C" fn(done: u32, total: u32);
+
+/// Same as `ProgressCallback`, with the `user_data` passed along with it.
+pub type ProgressCallbackWithData = extern "C" fn(user_data: *mut c_void, done: u32, total: u32);
+
+/// Parse `sudoku_grid` and pass the new solver to `f`, or `0` and the
+/// description of the error if it couldn't be parsed. Returns `SOLVER_ERROR` in the
+/// latter case, and `SOLVER_INVALID_ARGUMENT` without calling `f` if
+/// `sudoku_grid` is null.
+#[no_mangle]
+pub extern "C" fn solver_create(sudoku_grid: *const c_char, f: CreateCallback) -> u32 {
+    solver_create_inner(sudoku_grid, &mut |solver, error| f(solver, error))
+}
+
+/// Same as `solver_create`, but `user_data` is passed to `f` as is, e.g. to
+/// route the result back to the object that made the call.
+#[no_mangle]
+pub extern "C" fn solver_create_with_data(
+    sudoku_grid: *const c_char,
+    f: CreateCallbackWithData,
+    user_data: *mut c_void,
+) -> u32 {
+    solver_create_inner(sudoku_grid, &mut |solver, error| f(user_data, solver, error))
+}
+
+/// Same as `solver_create`, but the new solver is stored into `solver`
+/// instead of being passed to a callback. The description of the error
+/// is available via `solver_last_error_message`.
+#[no_mangle]
+pub extern "C" fn solver_create_into(
+    sudoku_grid: *const c_char,
+    solver: *mut SolverHandle,
+) -> u32 {
+    status(catch(|| {
+        non_null(sudoku_grid, "sudoku_grid")?;
+        non_null(solver, "solver")?;
+        let handle = create(sudoku_grid)?;
+        unsafe {
+            *solver = handle;
+        }
+        emit(SOLVER_EVENT_CREATED, handle);
+        Ok(())
+    }))
+}
+
+/// Parse `sudoku_grid` and register the new solver.
+fn create(sudoku_grid: *const c_char) -> Result<SolverHandle> {
+    let cstr = unsafe { CStr::from_ptr(sudoku_grid) }.to_str()?;
+    let solver = Solver::from_str(cstr)?;
+    Ok(lock(&SOLVERS).insert(Arc::new(Mutex::new(solver))))
+}
+
+fn solver_create_inner(
+    sudoku_grid: *const c_char,
+    f: &mut FnMut(SolverHandle, *const c_char),
+) -> u32 {
+    status(catch(|| {
+        non_null(sudoku_grid, "sudoku_grid")?;
+        match create(sudoku_grid) {
+            Ok(handle) => {
+                f(handle, ptr::null());
+                emit(SOLVER_EVENT_CREATED, handle);
+                Ok(())
+            }
+            Err(e) => {
+                // Mind that `c_err_str` must outlive `f` call!
+                let c_err_str = CString::new(e.description())?;
+                f(0, c_err_str.as_ptr());
+                Err(e)
+            }
+        }
+    }))
+}
+
+/// Solve `solver` and pass the solution to `f`, or null if there is none.
+/// Not finding a solution isn't a failure of the call.
+#[no_mangle]
+pub extern "C" fn solver_solve(solver: SolverHandle, f: SolveCallback) -> u32 {
+    solver_solve_inner(solver, &mut |solution| f(solution), &mut |_| {})
+}
+
+/// Same as `solver_solve`, but `user_data` is passed to `f` as is.
+#[no_mangle]
+pub extern "C" fn solver_solve_with_data(
+    solver: SolverHandle,
+    f: SolveCallbackWithData,
+    user_data: *mut c_void,
+) -> u32 {
+    solver_solve_inner(solver, &mut |solution| f(user_data, solution), &mut |_| {})
+}
+
+/// Same as `solver_solve`, but `progress` is called with progress of
+/// solving, before `f` is called. `progress` must not use `solver`.
+#[no_mangle]
+pub extern "C" fn solver_solve_with_progress(
+    solver: SolverHandle,
+    f: SolveCallback,
+    progress: ProgressCallback,
+) -> u32 {
+    solver_solve_inner(
+        solver,
+        &mut |solution| f(solution),
+        &mut |p: Progress| progress(p.done, p.total),
+    )
+}
+
+/// Same as `solver_solve_with_progress`, but `user_data` is passed to
+/// both `f` and `progress` as is.
+#[no_mangle]
+pub extern "C" fn solver_solve_with_progress_and_data(
+    solver: SolverHandle,
+    f: SolveCallbackWithData,
+    progress: ProgressCallbackWithData,
+    user_data: *mut c_void,
+) -> u32 {
+    solver_solve_inner(
+        solver,
+        &mut |solution| f(user_data, solution),
+        &mut |p: Progress| progress(user_data, p.done, p.total),
+    )
+}
+
+fn solver_solve_inner(
+    solver: SolverHandle,
+    f: &mut FnMut(*const c_char),
+    progress: &mut FnMut(Progress),
+) -> u32 {
+    status(catch(|| {
+        match with_solver(solver, |solver| solver.solve_with_progress(progress))? {
             Some(solution) => {
                 // Mind that `c_solution` must outlive `f` call!
-                let c_solution = CString::new(solution).expect("solution should be valid cstring");
+                let c_solution = CString::new(solution)?;
                 f(c_solution.as_ptr());
+                emit(SOLVER_EVENT_SOLVED, solver);
             }
             None => f(ptr::null()),
         }
+        Ok(())
+    }))
+}
+
+/// Same as `solver_solve`, but the solution is copied into `buf` of
+/// `buf_len` bytes, including the terminating nul, instead of being
+/// passed to a callback.
+///
+/// The size of the solution, including the nul, is stored into `needed`
+/// unless it is null. If it exceeds `buf_len`, nothing is copied and
+/// `SOLVER_BUFFER_TOO_SMALL` is returned, `buf` can be null in this case.
+/// Solutions are cached, so calling this again with a bigger buffer
+/// doesn't solve the grid twice. Returns `SOLVER_NOT_FOUND` if there is
+/// no solution.
+#[no_mangle]
+pub extern "C" fn solver_solve_into(
+    solver: SolverHandle,
+    buf: *mut c_char,
+    buf_len: usize,
+    needed: *mut usize,
+) -> u32 {
+    status(catch(|| {
+        let solution = solve(solver)?;
+        let bytes = solution.as_bytes_with_nul();
+        if !needed.is_null() {
+            unsafe {
+                *needed = bytes.len();
+            }
+        }
+        if bytes.len() > buf_len {
+            bail!(ErrorKind::BufferTooSmall(bytes.len()));
+        }
+        non_null(buf, "buf")?;
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, buf, bytes.len());
+        }
+        emit(SOLVER_EVENT_SOLVED, solver);
+        Ok(())
+    }))
+}
+
+/// Same as `solver_solve`, but the solution is stored into `solution`
+/// instead of being passed to a callback. The caller owns the string and
+/// must free it with `solver_string_free`. Returns `SOLVER_NOT_FOUND` if
+/// there is no solution.
+#[no_mangle]
+pub extern "C" fn solver_solve_owned(solver: SolverHandle, solution: *mut *mut c_char) -> u32 {
+    status(catch(|| {
+        non_null(solution, "solution")?;
+        let owned = solve(solver)?.into_raw();
+        unsafe {
+            *solution = owned;
+        }
+        emit(SOLVER_EVENT_SOLVED, solver);
+        Ok(())
+    }))
+}
+
+/// Free a string returned by the library, e.g. by `solver_solve_owned`.
+/// Does nothing if `string` is null.
+#[no_mangle]
+pub extern "C" fn solver_string_free(string: *mut c_char) -> u32 {
+    status(catch(|| {
+        if !string.is_null() {
+            unsafe {
+                let _ = CString::from_raw(string);
+            }
+        }
+        Ok(())
+    }))
+}
+
+/// Solution of `solver`, failing with `SolutionNotFound` if there is none.
+/// Callers emit `SOLVER_EVENT_SOLVED` once the solution is handed over.
+fn solve(solver: SolverHandle) -> Result<CString> {
+    match with_solver(solver, Solver::solve)? {
+        Some(solution) => Ok(CString::new(solution)?),
+        None => Err(context::Error::from(context::ErrorKind::SolutionNotFound).into()),
     }
 }
 
+/// Destroy `solver`, invalidating its handle.
 #[no_mangle]
-pub extern "C" fn solver_destroy(solver: *mut Solver) {
-    assert!(!solver.is_null());
-    unsafe {
-        let _ = Box::from_raw(solver);
+pub extern "C" fn solver_destroy(solver: SolverHandle) -> u32 {
+    status(catch(|| {
+        let removed = lock(&SOLVERS).remove(solver);
+        if removed.is_none() {
+            bail!(ErrorKind::InvalidHandle(solver));
+        }
+        emit(SOLVER_EVENT_DESTROYED, solver);
+        Ok(())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(solver_last_error_message()) }
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn status_codes() {
+        extern "C" fn ignore_created(_: SolverHandle, _: *const c_char) {}
+        extern "C" fn ignore_solution(_: *const c_char) {}
+
+        assert_eq!(solver_create(ptr::null(), ignore_created), SOLVER_INVALID_ARGUMENT);
+        assert_eq!(last_error(), "invalid argument: `sudoku_grid` is null");
+        assert_eq!(solver_solve(0, ignore_solution), SOLVER_INVALID_HANDLE);
+        assert_eq!(solver_destroy(0), SOLVER_INVALID_HANDLE);
+
+        let bad_grid = CString::new("not a grid").unwrap();
+        assert_eq!(solver_create(bad_grid.as_ptr(), ignore_created), SOLVER_ERROR);
+        assert!(!last_error().is_empty());
+    }
+
+    #[test]
+    fn panics_are_caught() {
+        assert_eq!(status(catch(|| -> Result<()> { panic!("oops") })), SOLVER_PANIC);
+        assert_eq!(last_error(), "panic: oops");
+        assert_eq!(status(catch(|| Ok(()))), SOLVER_OK);
+        // Successful calls leave the last error as is.
+        assert_eq!(last_error(), "panic: oops");
+    }
+
+    #[test]
+    fn user_data() {
+        extern "C" fn created(user_data: *mut c_void, solver: SolverHandle, _: *const c_char) {
+            unsafe { *(user_data as *mut SolverHandle) = solver };
+        }
+        extern "C" fn solved(user_data: *mut c_void, solution: *const c_char) {
+            let solution = unsafe { CStr::from_ptr(solution) }.to_str().unwrap();
+            unsafe { *(user_data as *mut Option<String>) = Some(solution.to_string()) };
+        }
+        extern "C" fn progress(user_data: *mut c_void, done: u32, total: u32) {
+            assert!(done <= total);
+            unsafe { *(user_data as *mut Option<String>) = None };
+        }
+
+        let grid = CString::new(include_str!("sudoku.txt")).unwrap();
+        let mut solver: SolverHandle = 0;
+        let status = solver_create_with_data(
+            grid.as_ptr(),
+            created,
+            &mut solver as *mut SolverHandle as *mut c_void,
+        );
+        assert_eq!(status, SOLVER_OK);
+        assert_ne!(solver, 0);
+
+        let mut solution: Option<String> = None;
+        let user_data = &mut solution as *mut Option<String> as *mut c_void;
+        assert_eq!(solver_solve_with_data(solver, solved, user_data), SOLVER_OK);
+        let expected = solution.take().unwrap();
+        assert_eq!(
+            solver_solve_with_progress_and_data(solver, solved, progress, user_data),
+            SOLVER_OK
+        );
+        assert_eq!(solution, Some(expected));
+
+        assert_eq!(solver_destroy(solver), SOLVER_OK);
+    }
+
+    #[test]
+    fn without_callbacks() {
+        let grid = CString::new(include_str!("sudoku.txt")).unwrap();
+        let expected = include_str!("sudoku_solution.txt");
+        let mut solver: SolverHandle = 0;
+        assert_eq!(solver_create_into(grid.as_ptr(), &mut solver), SOLVER_OK);
+
+        let mut needed = 0;
+        let status = solver_solve_into(solver, ptr::null_mut(), 0, &mut needed);
+        assert_eq!(status, SOLVER_BUFFER_TOO_SMALL);
+        assert_eq!(needed, expected.len() + 1);
+
+        let mut buf = vec![0 as c_char; needed];
+        let status = solver_solve_into(solver, buf.as_mut_ptr(), buf.len(), ptr::null_mut());
+        assert_eq!(status, SOLVER_OK);
+        let solution = unsafe { CStr::from_ptr(buf.as_ptr()) };
+        assert_eq!(solution.to_str().unwrap(), expected);
+
+        let mut owned: *mut c_char = ptr::null_mut();
+        assert_eq!(solver_solve_owned(solver, &mut owned), SOLVER_OK);
+        assert_eq!(unsafe { CStr::from_ptr(owned) }.to_str().unwrap(), expected);
+        assert_eq!(solver_string_free(owned), SOLVER_OK);
+        assert_eq!(solver_destroy(solver), SOLVER_OK);
+
+        let bad_grid = CString::new(include_str!("bad_sudoku.txt")).unwrap();
+        assert_eq!(solver_create_into(bad_grid.as_ptr(), &mut solver), SOLVER_OK);
+        assert_eq!(solver_solve_owned(solver, &mut owned), SOLVER_NOT_FOUND);
+        assert_eq!(solver_destroy(solver), SOLVER_OK);
+    }
+
+    #[test]
+    fn solved_event_once_per_solution() {
+        lazy_static! {
+            static ref EVENTS: Mutex<Vec<(u32, SolverHandle)>> = Mutex::new(Vec::new());
+        }
+        extern "C" fn record(event: u32, solver: SolverHandle) {
+            lock(&EVENTS).push((event, solver));
+        }
+        // Other tests run concurrently, count only the events of `solver`.
+        fn solved(solver: SolverHandle) -> usize {
+            lock(&EVENTS)
+                .iter()
+                .filter(|&&event| event == (SOLVER_EVENT_SOLVED, solver))
+                .count()
+        }
+
+        assert_eq!(solver_set_event_callback(Some(record)), SOLVER_OK);
+        let grid = CString::new(include_str!("sudoku.txt")).unwrap();
+        let mut solver: SolverHandle = 0;
+        assert_eq!(solver_create_into(grid.as_ptr(), &mut solver), SOLVER_OK);
+
+        // Probe for the size first, then retry with a big enough buffer.
+        let mut needed = 0;
+        assert_eq!(
+            solver_solve_into(solver, ptr::null_mut(), 0, &mut needed),
+            SOLVER_BUFFER_TOO_SMALL
+        );
+        assert_eq!(solved(solver), 0);
+        let mut buf = vec![0 as c_char; needed];
+        assert_eq!(solver_solve_into(solver, buf.as_mut_ptr(), needed, &mut needed), SOLVER_OK);
+        assert_eq!(solved(solver), 1);
+
+        let mut owned: *mut c_char = ptr::null_mut();
+        assert_eq!(solver_solve_owned(solver, ptr::null_mut()), SOLVER_INVALID_ARGUMENT);
+        assert_eq!(solved(solver), 1);
+        assert_eq!(solver_solve_owned(solver, &mut owned), SOLVER_OK);
+        assert_eq!(solver_string_free(owned), SOLVER_OK);
+        assert_eq!(solved(solver), 2);
+
+        assert_eq!(solver_destroy(solver), SOLVER_OK);
+        assert_eq!(solver_set_event_callback(None), SOLVER_OK);
+    }
+
+    #[test]
+    fn stale_handles() {
+        let grid = CString::new(include_str!("sudoku.txt")).unwrap();
+        let mut solver: SolverHandle = 0;
+        assert_eq!(solver_create_into(grid.as_ptr(), &mut solver), SOLVER_OK);
+        assert_eq!(solver_destroy(solver), SOLVER_OK);
+
+        // Double destroy and use after destroy are detected.
+        assert_eq!(solver_destroy(solver), SOLVER_INVALID_HANDLE);
+        let mut owned: *mut c_char = ptr::null_mut();
+        assert_eq!(solver_solve_owned(solver, &mut owned), SOLVER_INVALID_HANDLE);
+        assert!(owned.is_null());
+
+        // Even if the slot has been reused by another solver.
+        let mut other: SolverHandle = 0;
+        assert_eq!(solver_create_into(grid.as_ptr(), &mut other), SOLVER_OK);
+        assert_eq!(solver_destroy(solver), SOLVER_INVALID_HANDLE);
+        assert_eq!(solver_destroy(other), SOLVER_OK);
+    }
+
+    #[test]
+    fn concurrent_use() {
+        use std::thread;
+
+        fn solve_owned(solver: SolverHandle) -> (u32, Option<String>) {
+            let mut owned: *mut c_char = ptr::null_mut();
+            let status = solver_solve_owned(solver, &mut owned);
+            let solution = if status == SOLVER_OK {
+                let solution = unsafe { CStr::from_ptr(owned) }.to_str().unwrap().to_string();
+                assert_eq!(solver_string_free(owned), SOLVER_OK);
+                Some(solution)
+            } else {
+                None
+            };
+            (status, solution)
+        }
+
+        let threads = 8;
+        let grid = include_str!("sudoku.txt");
+        let expected = include_str!("sudoku_solution.txt");
+
+        // Distinct solvers, created, solved and destroyed by each thread.
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                thread::spawn(move || for _ in 0..20 {
+                    let grid = CString::new(grid).unwrap();
+                    let mut solver: SolverHandle = 0;
+                    assert_eq!(solver_create_into(grid.as_ptr(), &mut solver), SOLVER_OK);
+                    assert_eq!(solve_owned(solver), (SOLVER_OK, Some(expected.to_string())));
+                    assert_eq!(solver_destroy(solver), SOLVER_OK);
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // The same solver, destroyed while it is being solved by the others.
+        let grid = CString::new(grid).unwrap();
+        let mut solver: SolverHandle = 0;
+        assert_eq!(solver_create_into(grid.as_ptr(), &mut solver), SOLVER_OK);
+        let handles: Vec<_> = (0..threads)
+            .map(|i| {
+                thread::spawn(move || if i == threads / 2 {
+                    vec![solver_destroy(solver)]
+                } else {
+                    (0..20)
+                        .map(|_| match solve_owned(solver) {
+                            (SOLVER_OK, Some(ref solution)) if solution == expected => SOLVER_OK,
+                            (status, None) => status,
+                            _ => panic!("wrong solution"),
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+        let statuses: Vec<u32> = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect();
+        assert!(
+            statuses
+                .iter()
+                .all(|&status| status == SOLVER_OK || status == SOLVER_INVALID_HANDLE)
+        );
+        assert_eq!(solver_destroy(solver), SOLVER_INVALID_HANDLE);
+    }
+
+    #[test]
+    fn abi_version_and_features() {
+        assert_eq!(turbosolver_abi_version(), TURBOSOLVER_ABI_VERSION);
+        let feature = |name: &str| turbosolver_has_feature(CString::new(name).unwrap().as_ptr());
+        assert!(feature("handles"));
+        assert_eq!(feature("rpc"), cfg!(unix));
+        assert!(!feature("teleport"));
+        assert!(!feature(""));
+        assert!(!turbosolver_has_feature(ptr::null()));
     }
 }
@@ -0,0 +1,106 @@
+//! Handles - integer ids of objects owned by the library, handed out over
+//! FFI instead of raw pointers.
+//!
+//! A handle combines the index of a slot in the table with the generation
+//! of the slot, which is bumped every time the slot is freed. This way
+//! stale handles are detected even after their slot has been reused.
+
+/// Handle of an object in a `HandleTable`. `0` is never a valid handle.
+pub type Handle = u64;
+
+struct Slot<T> {
+    /// Generations start at 1, so that handles are never 0.
+    generation: u32,
+    value: Option<T>,
+}
+
+pub struct HandleTable<T> {
+    slots: Vec<Slot<T>>,
+    /// Indices of the free slots.
+    free: Vec<usize>,
+}
+
+impl<T> HandleTable<T> {
+    pub fn new() -> HandleTable<T> {
+        HandleTable {
+            slots: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    pub fn insert(&mut self, value: T) -> Handle {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(Slot {
+                    generation: 1,
+                    value: None,
+                });
+                self.slots.len() - 1
+            }
+        };
+        let slot = &mut self.slots[index];
+        slot.value = Some(value);
+        (slot.generation as u64) << 32 | index as u64
+    }
+
+    /// Object behind `handle`, `None` if it is invalid or stale.
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        match self.index(handle) {
+            Some(index) => self.slots[index].value.as_ref(),
+            None => None,
+        }
+    }
+
+    /// Remove the object behind `handle`, invalidating the handle.
+    /// Returns `None` if it is invalid or stale.
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        let index = match self.index(handle) {
+            Some(index) => index,
+            None => return None,
+        };
+        let slot = &mut self.slots[index];
+        slot.generation = slot.generation.wrapping_add(1);
+        if slot.generation == 0 {
+            slot.generation = 1;
+        }
+        self.free.push(index);
+        slot.value.take()
+    }
+
+    /// Index of the occupied slot `handle` points to.
+    fn index(&self, handle: Handle) -> Option<usize> {
+        let index = (handle & 0xffff_ffff) as usize;
+        let generation = (handle >> 32) as u32;
+        match self.slots.get(index) {
+            Some(slot) if slot.generation == generation && slot.value.is_some() => Some(index),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stale_handles() {
+        let mut table = HandleTable::new();
+        let first = table.insert("first");
+        assert_ne!(first, 0);
+        assert_eq!(table.get(first), Some(&"first"));
+
+        assert_eq!(table.remove(first), Some("first"));
+        assert_eq!(table.get(first), None);
+        assert_eq!(table.remove(first), None);
+
+        // The slot is reused, but the old handle stays invalid.
+        let second = table.insert("second");
+        assert_ne!(second, first);
+        assert_eq!(table.get(first), None);
+        assert_eq!(table.get(second), Some(&"second"));
+
+        assert_eq!(table.get(0), None);
+        assert_eq!(table.get(second + 1), None);
+    }
+}
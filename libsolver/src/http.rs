@@ -1,43 +1,32 @@
 use rocket;
-use rocket::{State, Response, Request};
-use rocket::response::Responder;
+use rocket::{Outcome, State, Response, Request};
+use rocket::request::{self, FromRequest};
+use rocket::response::{Content, Responder, Stream};
 use rocket::http::{Status, ContentType};
 use rocket_contrib::{Json, Value};
-use executor::{Executor, Req, ReqKind, Resp, RespKind};
-use std::sync::mpsc::{channel, Receiver};
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Mutex;
+use executor::{Config, ExecutorOptions, Priority, ReqKind, RespKind, SOLVER_OVERFLOW_BLOCK};
+use client::{Client, Notification, ResponseFuture};
+use context::{unix_millis, EventKind, Limits, SolverInfo, Usage};
+use solver::{Assignment, Progress};
+use futures::Future;
+use std::io::{self, Cursor, Read};
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::Duration;
 use error::*;
+use ffi::{catch, status};
 
-/// Synchronous wrapper around `Executor`.
-///
-/// Allows to use `Executor` as if it was fully synchronous.
-struct SyncExecutor {
-    executor: Executor,
-    receiver: Receiver<Resp>,
-    next_msg_id: AtomicUsize,
-}
-
-impl SyncExecutor {
-    fn new() -> SyncExecutor {
-        let (tx, rx) = channel();
-        let executor = Executor::new(move |resp| { tx.send(resp).unwrap(); });
-        SyncExecutor {
-            executor,
-            receiver: rx,
-            next_msg_id: AtomicUsize::new(0),
-        }
-    }
+/// Requests are processed with the priority taken from the `X-Priority`
+/// header: `low`, `normal` (the default) or `high`.
+impl<'a, 'r> FromRequest<'a, 'r> for Priority {
+    type Error = ();
 
-    fn send_sync(&mut self, req_kind: ReqKind) -> Result<RespKind> {
-        let msg_id = self.next_msg_id.fetch_add(1, Ordering::SeqCst);
-        self.executor.send(Req {
-            id: msg_id,
-            kind: req_kind,
-        });
-        let resp = self.receiver.recv().unwrap();
-        assert_eq!(msg_id, resp.id);
-        resp.kind
+    fn from_request(request: &'a Request<'r>) -> request::Outcome<Priority, ()> {
+        match request.headers().get_one("X-Priority") {
+            None | Some("normal") => Outcome::Success(Priority::Normal),
+            Some("low") => Outcome::Success(Priority::Low),
+            Some("high") => Outcome::Success(Priority::High),
+            Some(_) => Outcome::Failure((Status::BadRequest, ())),
+        }
     }
 }
 
@@ -47,10 +36,13 @@ struct CreateSolverReq {
 }
 
 #[post("/", data = "<req>")]
-fn create(req: Json<CreateSolverReq>, ctx: State<Mutex<SyncExecutor>>) -> Result<Json<Value>> {
-    let mut sync_exec = ctx.lock().unwrap();
+fn create(
+    req: Json<CreateSolverReq>,
+    client: State<Client>,
+    priority: Priority,
+) -> Result<Json<Value>> {
     let req = ReqKind::CreateSolver { grid: req.grid.clone() };
-    let solver_id = match sync_exec.send_sync(req)? {
+    let solver_id = match client.send_with_priority(req, priority).wait()? {
         RespKind::SolverCreated { id } => id,
         _ => panic!("Unexpected variant!"),
     };
@@ -61,10 +53,9 @@ fn create(req: Json<CreateSolverReq>, ctx: State<Mutex<SyncExecutor>>) -> Result
 }
 
 #[get("/<id>/solution")]
-fn solution(id: usize, ctx: State<Mutex<SyncExecutor>>) -> Result<Json<Value>> {
-    let mut sync_exec = ctx.lock().unwrap();
+fn solution(id: usize, client: State<Client>, priority: Priority) -> Result<Json<Value>> {
     let req = ReqKind::Solve { id };
-    let solution = match sync_exec.send_sync(req)? {
+    let solution = match client.send_with_priority(req, priority).wait()? {
         RespKind::SolverResult { solution } => solution,
         _ => panic!("Unexpected variant!"),
     };
@@ -74,11 +65,385 @@ fn solution(id: usize, ctx: State<Mutex<SyncExecutor>>) -> Result<Json<Value>> {
     Ok(resp)
 }
 
+#[post("/<id>/clone")]
+fn clone_solver(
+    id: usize,
+    client: State<Client>,
+    priority: Priority,
+) -> Result<Json<Value>> {
+    let req = ReqKind::CloneSolver { id };
+    let solver_id = match client.send_with_priority(req, priority).wait()? {
+        RespKind::SolverCloned { id } => id,
+        _ => panic!("Unexpected variant!"),
+    };
+    let resp = Json(json!({
+        "id": solver_id as u32
+    }));
+    Ok(resp)
+}
+
+#[derive(Deserialize)]
+struct SolveAssumingReq {
+    assumptions: Vec<Assignment>,
+}
+
+#[post("/<id>/solution", data = "<req>")]
+fn solution_assuming(
+    id: usize,
+    req: Json<SolveAssumingReq>,
+    client: State<Client>,
+    priority: Priority,
+) -> Result<Json<Value>> {
+    let req = ReqKind::SolveAssuming {
+        id,
+        assumptions: req.assumptions.clone(),
+    };
+    let solution = match client.send_with_priority(req, priority).wait()? {
+        RespKind::SolverResult { solution } => solution,
+        _ => panic!("Unexpected variant!"),
+    };
+    let resp = Json(json!({
+        "solution": solution
+    }));
+    Ok(resp)
+}
+
+#[get("/")]
+fn list(client: State<Client>, priority: Priority) -> Result<Json<Value>> {
+    let req = ReqKind::ListSolvers;
+    let ids = match client.send_with_priority(req, priority).wait()? {
+        RespKind::SolverList { ids } => ids,
+        _ => panic!("Unexpected variant!"),
+    };
+    let resp = Json(json!({
+        "ids": ids
+    }));
+    Ok(resp)
+}
+
+#[get("/usage")]
+fn usage(client: State<Client>, priority: Priority) -> Result<Json<Value>> {
+    let req = ReqKind::GetUsage;
+    let (usage, limits) = match client.send_with_priority(req, priority).wait()? {
+        RespKind::Usage { usage, limits } => (usage, limits),
+        _ => panic!("Unexpected variant!"),
+    };
+    Ok(Json(usage_json(usage, limits)))
+}
+
+fn usage_json(usage: Usage, limits: Limits) -> Value {
+    json!({
+        "bytes": usage.bytes,
+        "solvers": usage.solvers,
+        "max_bytes": limits.max_bytes,
+        "max_solvers": limits.max_solvers
+    })
+}
+
+#[get("/<id>")]
+fn describe(id: usize, client: State<Client>, priority: Priority) -> Result<Json<Value>> {
+    let req = ReqKind::DescribeSolver { id };
+    let info = match client.send_with_priority(req, priority).wait()? {
+        RespKind::SolverInfo { info } => info,
+        _ => panic!("Unexpected variant!"),
+    };
+    Ok(Json(solver_info_json(info)))
+}
+
+fn solver_info_json(info: SolverInfo) -> Value {
+    json!({
+        "id": info.id as u32,
+        "grid": info.grid,
+        "current_grid": info.current_grid,
+        "created_at": unix_millis(info.created),
+        "last_used_at": unix_millis(info.last_used),
+        "solved": info.solved
+    })
+}
+
+/// A single request of a batch, tagged with `op`.
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum BatchItem {
+    Create { grid: String },
+    Solve { id: usize },
+    SolveAssuming {
+        id: usize,
+        assumptions: Vec<Assignment>,
+    },
+    Delete { id: usize },
+    List,
+    Describe { id: usize },
+    Clone { id: usize },
+    Usage,
+}
+
+impl From<BatchItem> for ReqKind {
+    fn from(item: BatchItem) -> ReqKind {
+        match item {
+            BatchItem::Create { grid } => ReqKind::CreateSolver { grid },
+            BatchItem::Solve { id } => ReqKind::Solve { id },
+            BatchItem::SolveAssuming { id, assumptions } => {
+                ReqKind::SolveAssuming { id, assumptions }
+            }
+            BatchItem::Delete { id } => ReqKind::Destroy { id },
+            BatchItem::List => ReqKind::ListSolvers,
+            BatchItem::Describe { id } => ReqKind::DescribeSolver { id },
+            BatchItem::Clone { id } => ReqKind::CloneSolver { id },
+            BatchItem::Usage => ReqKind::GetUsage,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BatchReq {
+    requests: Vec<BatchItem>,
+    #[serde(default)]
+    stop_on_error: bool,
+}
+
+/// JSON of a batched result, same as the body of the corresponding route.
+fn result_json(result: Result<RespKind>) -> Value {
+    let resp_kind = match result {
+        Ok(resp_kind) => resp_kind,
+        Err(e) => {
+            return json!({
+                "description": e.description()
+            })
+        }
+    };
+    match resp_kind {
+        RespKind::SolverCreated { id } | RespKind::SolverCloned { id } => json!({
+            "id": id as u32
+        }),
+        RespKind::SolverResult { solution } => json!({
+            "solution": solution
+        }),
+        RespKind::Destroyed | RespKind::EventsSubscribed => Value::Null,
+        RespKind::SolverList { ids } => json!({
+            "ids": ids
+        }),
+        RespKind::SolverInfo { info } => solver_info_json(info),
+        RespKind::Usage { usage, limits } => usage_json(usage, limits),
+        RespKind::Batch { results } => json!({
+            "results": results.into_iter().map(result_json).collect::<Vec<_>>()
+        }),
+        RespKind::Handshake { version, features } => json!({
+            "protocol_version": version,
+            "features": features
+        }),
+    }
+}
+
+/// Process several requests one after another, see `ReqKind::Batch`.
+///
+/// Failed requests don't fail the whole batch, their results are
+/// error descriptions instead.
+#[post("/batch", data = "<req>")]
+fn batch(req: Json<BatchReq>, client: State<Client>, priority: Priority) -> Result<Json<Value>> {
+    let Json(BatchReq {
+        requests,
+        stop_on_error,
+    }) = req;
+    let req = ReqKind::Batch {
+        kinds: requests.into_iter().map(ReqKind::from).collect(),
+        stop_on_error,
+    };
+    let resp_kind = client.send_with_priority(req, priority).wait()?;
+    Ok(Json(result_json(Ok(resp_kind))))
+}
+
+/// Size of a single server-sent event frame.
+const SSE_FRAME_SIZE: usize = 8192;
+
+/// Interval of keep-alive comments on idle event streams. A stream is only
+/// written to when there is something to send, so without them a client
+/// that went away isn't noticed until the next event.
+const SSE_KEEP_ALIVE_SECS: u64 = 15;
+
+/// Whether to pad server-sent events to `SSE_FRAME_SIZE`, taken from the
+/// `sse_padding` rocket config parameter, on by default.
+///
+/// Workaround: rocket 0.3 fills the whole chunk of a streamed body, see
+/// `Stream::chunked`, before handing it to hyper, which doesn't flush it
+/// either. So unpadded events are delivered only once 8 KiB of them pile up.
+/// Padding fills the buffer (or several of them, if the event doesn't fit)
+/// with every event. Turn it off when the stream is consumed in full, e.g.
+/// in tests, or once the server flushes streamed chunks as they come.
+struct SsePadding(bool);
+
+/// Encode `json` as a server-sent event, padded to the frame size if `pad`.
+fn sse_frame(json: &Value, pad: bool) -> Vec<u8> {
+    pad_frame(format!("data: {}\n\n", json), pad)
+}
+
+/// Comment sent when there were no events for `SSE_KEEP_ALIVE_SECS`.
+fn sse_keep_alive(pad: bool) -> Vec<u8> {
+    pad_frame(": keep-alive\n\n".to_owned(), pad)
+}
+
+fn pad_frame(data: String, pad: bool) -> Vec<u8> {
+    if !pad {
+        return data.into_bytes();
+    }
+
+    // Padding is a comment line, which is ignored by clients.
+    let frame_len = (data.len() + 2 + SSE_FRAME_SIZE - 1) / SSE_FRAME_SIZE * SSE_FRAME_SIZE;
+    let padding_len = frame_len - data.len() - 2;
+    let mut frame = Vec::with_capacity(frame_len);
+    frame.push(b':');
+    frame.extend(::std::iter::repeat(b' ').take(padding_len));
+    frame.push(b'\n');
+    frame.extend_from_slice(data.as_bytes());
+    frame
+}
+
+fn event_stream_content<R: Read>(reader: R) -> Content<Stream<R>> {
+    let stream = Stream::chunked(reader, SSE_FRAME_SIZE as u64);
+    Content(ContentType::new("text", "event-stream"), stream)
+}
+
+/// Body of the event stream, producing one padded frame per notification.
+struct EventStream {
+    notifications: Receiver<Notification>,
+    pad: bool,
+    keep_alive: Duration,
+    frame: Cursor<Vec<u8>>,
+}
+
+impl EventStream {
+    fn new(notifications: Receiver<Notification>, pad: bool) -> EventStream {
+        EventStream {
+            notifications,
+            pad,
+            keep_alive: Duration::from_secs(SSE_KEEP_ALIVE_SECS),
+            frame: Cursor::new(Vec::new()),
+        }
+    }
+
+    fn encode_frame(&self, notification: Notification) -> Vec<u8> {
+        let json = match notification {
+            Notification::Event(event) => {
+                let kind = match event.kind {
+                    EventKind::Created => "created",
+                    EventKind::Solved => "solved",
+                    EventKind::Destroyed => "destroyed",
+                };
+                json!({
+                    "event": kind,
+                    "id": event.id as u32
+                })
+            }
+            Notification::WorkerRestarted(message) => json!({
+                "event": "worker_restarted",
+                "message": message
+            }),
+        };
+        sse_frame(&json, self.pad)
+    }
+}
+
+impl Read for EventStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.frame.position() as usize == self.frame.get_ref().len() {
+            match self.notifications.recv_timeout(self.keep_alive) {
+                Ok(notification) => self.frame = Cursor::new(self.encode_frame(notification)),
+                // Writing the comment fails if the client has disconnected,
+                // which drops the stream and unsubscribes it.
+                Err(RecvTimeoutError::Timeout) => {
+                    self.frame = Cursor::new(sse_keep_alive(self.pad))
+                }
+                // Executor is gone, end the stream.
+                Err(RecvTimeoutError::Disconnected) => return Ok(0),
+            }
+        }
+        self.frame.read(buf)
+    }
+}
+
+#[get("/events")]
+fn events(client: State<Client>, padding: State<SsePadding>) -> Content<Stream<EventStream>> {
+    let notifications = client.subscribe();
+    event_stream_content(EventStream::new(notifications, padding.0))
+}
+
+/// Body of a progress stream: one frame per progress update, followed by
+/// a frame with either the result or the error description.
+struct ProgressStream {
+    progress: Receiver<Progress>,
+    resp: Option<ResponseFuture>,
+    /// Converts successful response into the final frame.
+    result_json: fn(RespKind) -> Value,
+    pad: bool,
+    frame: Cursor<Vec<u8>>,
+}
+
+impl ProgressStream {
+    fn new(
+        (resp, progress): (ResponseFuture, Receiver<Progress>),
+        result_json: fn(RespKind) -> Value,
+        pad: bool,
+    ) -> ProgressStream {
+        ProgressStream {
+            progress,
+            resp: Some(resp),
+            result_json,
+            pad,
+            frame: Cursor::new(Vec::new()),
+        }
+    }
+}
+
+impl Read for ProgressStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.frame.position() as usize == self.frame.get_ref().len() {
+            let json = match self.progress.recv() {
+                Ok(progress) => json!({
+                    "done": progress.done,
+                    "total": progress.total
+                }),
+                // Progress is disconnected once the response is ready.
+                Err(_) => match self.resp.take().map(Future::wait) {
+                    Some(Ok(resp_kind)) => (self.result_json)(resp_kind),
+                    Some(Err(e)) => json!({
+                        "description": e.description()
+                    }),
+                    None => return Ok(0),
+                },
+            };
+            self.frame = Cursor::new(sse_frame(&json, self.pad));
+        }
+        self.frame.read(buf)
+    }
+}
+
+/// Same as `solution`, but streams progress of solving before the solution.
+/// Solving is a single step, so there are two progress frames.
+#[get("/<id>/solution/progress")]
+fn solution_progress(
+    id: usize,
+    client: State<Client>,
+    padding: State<SsePadding>,
+    priority: Priority,
+) -> Content<Stream<ProgressStream>> {
+    fn result_json(resp_kind: RespKind) -> Value {
+        match resp_kind {
+            RespKind::SolverResult { solution } => json!({
+                "solution": solution
+            }),
+            _ => panic!("Unexpected variant!"),
+        }
+    }
+
+    let req = ReqKind::Solve { id };
+    let sent = client.send_with_progress(req, priority);
+    event_stream_content(ProgressStream::new(sent, result_json, padding.0))
+}
+
 #[delete("/<id>")]
-fn delete(id: usize, ctx: State<Mutex<SyncExecutor>>) -> Result<()> {
-    let mut sync_exec = ctx.lock().unwrap();
+fn delete(id: usize, client: State<Client>, priority: Priority) -> Result<()> {
     let req = ReqKind::Destroy { id };
-    match sync_exec.send_sync(req)? {
+    match client.send_with_priority(req, priority).wait()? {
         RespKind::Destroyed => {}
         _ => panic!("Unexpected variant!"),
     };
@@ -87,15 +452,18 @@ fn delete(id: usize, ctx: State<Mutex<SyncExecutor>>) -> Result<()> {
 
 impl<'a> Responder<'a> for Error {
     fn respond_to(self, _: &Request) -> ::std::result::Result<Response<'static>, Status> {
-        use std::io::Cursor;
-
+        let status = match *self.kind() {
+            ErrorKind::Internal(_) => Status::InternalServerError,
+            ErrorKind::Overloaded => Status::ServiceUnavailable,
+            _ => Status::BadRequest,
+        };
         let description = self.description();
         let resp_body = json!({
                 "description": description,
             }).to_string();
 
         let resp = Response::build()
-            .status(Status::BadRequest)
+            .status(status)
             .header(ContentType::JSON)
             .sized_body(Cursor::new(resp_body))
             .finalize();
@@ -103,25 +471,81 @@ impl<'a> Responder<'a> for Error {
     }
 }
 
-fn create_rocket() -> rocket::Rocket {
-    let sync_exec = Mutex::new(SyncExecutor::new());
-    rocket::ignite().manage(sync_exec).mount(
+/// Rocket serving the API, configured from the environment and `Rocket.toml`,
+/// with an executor configured with `config`.
+pub fn create_rocket(config: Config) -> rocket::Rocket {
+    mount(rocket::ignite(), config)
+}
+
+/// Mount the API onto `rocket`, served by an executor configured with `config`.
+pub fn mount(rocket: rocket::Rocket, config: Config) -> rocket::Rocket {
+    let client = Client::with_config(config);
+    let padding = rocket.config().get_bool("sse_padding").unwrap_or(true);
+    rocket.manage(client).manage(SsePadding(padding)).mount(
         "/",
         routes![
             create,
             solution,
             delete,
+            list,
+            describe,
+            clone_solver,
+            solution_assuming,
+            usage,
+            events,
+            solution_progress,
+            batch,
         ],
     )
 }
 
 /// Deploy and run http server instance.
 ///
+/// Be aware! This will block the calling thread. Returns only if the
+/// server has failed to launch, with the status of the failure.
+#[no_mangle]
+pub extern "C" fn http_deploy() -> u32 {
+    http_deploy_with_workers(1)
+}
+
+/// Same as `http_deploy`, but requests are processed by a pool of `workers` threads.
+///
+/// Be aware! This will block the calling thread.
+#[no_mangle]
+pub extern "C" fn http_deploy_with_workers(workers: usize) -> u32 {
+    http_deploy_with_options(ExecutorOptions {
+        workers,
+        max_pending: 0,
+        overflow: SOLVER_OVERFLOW_BLOCK,
+        max_bytes: 0,
+        max_solvers: 0,
+        journal_path: ::std::ptr::null(),
+    })
+}
+
+/// Same as `http_deploy`, but the executor is configured with `options`.
+/// Requests rejected due to overflow are responded with 503.
+///
 /// Be aware! This will block the calling thread.
 #[no_mangle]
-pub extern "C" fn http_deploy() {
-    let rocket = create_rocket();
-    rocket.launch();
+pub extern "C" fn http_deploy_with_options(options: ExecutorOptions) -> u32 {
+    status(catch(|| {
+        // The header requires `journal_path` to be null or a valid string
+        // for the duration of the call.
+        let journal_path = unsafe { options.journal_path()? };
+        deploy(Config::from_options(options, journal_path)?)
+    }))
+}
+
+/// Same as `http_deploy_with_options`, but for Rust hosts, which can use
+/// everything `Config` offers, e.g. interceptors.
+///
+/// Be aware! This will block the calling thread. Returns only if the
+/// server has failed to launch.
+pub fn deploy(config: Config) -> Result<()> {
+    let error = create_rocket(config).launch();
+    // `kind` marks the error as handled, otherwise it panics on drop.
+    bail!("couldn't launch the server: {}", error.kind())
 }
 
 #[cfg(target_os = "android")]
@@ -130,22 +554,29 @@ pub mod jni {
     extern crate jni;
 
     use super::*;
+    use capnproto::jni::throw_on_failure;
+    use ffi::SOLVER_OK;
     use self::jni::JNIEnv;
     use self::jni::objects::JClass;
 
     #[no_mangle]
     pub extern "C" fn Java_me_pepyakin_turbosolver_LocalHttpTurboSolverFactory_deploy(
-        _env: JNIEnv,
+        env: JNIEnv,
         _: JClass,
     ) {
-        http_deploy();
+        let status = http_deploy();
+        let deployed = if status == SOLVER_OK { Ok(()) } else { Err(status) };
+        throw_on_failure(&env, deployed, ())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use executor::Config;
     use rocket::local::{Client, LocalResponse};
     use rocket::http::{ContentType, Status};
+    use rocket_contrib::Value;
+    use serde_json;
 
     fn create_custom<'c>(client: &'c Client, grid: &str) -> LocalResponse<'c> {
         client
@@ -172,9 +603,45 @@ mod tests {
         client.delete(format!("/{}", id)).dispatch()
     }
 
+    fn clone_solver(client: &Client, id: usize) -> LocalResponse {
+        client.post(format!("/{}/clone", id)).dispatch()
+    }
+
+    fn solution_assuming(client: &Client, id: usize, row: u8, col: u8, digit: u8) -> LocalResponse {
+        client
+            .post(format!("/{}/solution", id))
+            .header(ContentType::JSON)
+            .body(
+                json!({
+                    "assumptions": [{ "row": row, "col": col, "digit": digit }]
+                }).to_string(),
+            )
+            .dispatch()
+    }
+
+    fn list(client: &Client) -> LocalResponse {
+        client.get("/").dispatch()
+    }
+
+    fn describe(client: &Client, id: usize) -> LocalResponse {
+        client.get(format!("/{}", id)).dispatch()
+    }
+
+    fn usage(client: &Client) -> LocalResponse {
+        client.get("/usage").dispatch()
+    }
+
+    fn events(client: &Client) -> LocalResponse {
+        client.get("/events").dispatch()
+    }
+
+    fn body_json(response: &mut LocalResponse) -> Value {
+        serde_json::from_str(&response.body_string().unwrap()).unwrap()
+    }
+
     #[test]
     fn test_create() {
-        let rocket = super::create_rocket();
+        let rocket = super::create_rocket(Config::default());
         let client = Client::new(rocket).unwrap();
         let mut response = create(&client);
 
@@ -189,7 +656,7 @@ mod tests {
 
     #[test]
     fn test_solution() {
-        let rocket = super::create_rocket();
+        let rocket = super::create_rocket(Config::default());
         let client = Client::new(rocket).unwrap();
         let _ = create(&client);
         let mut response = solution(&client, 0);
@@ -207,7 +674,7 @@ mod tests {
 
     #[test]
     fn test_delete() {
-        let rocket = super::create_rocket();
+        let rocket = super::create_rocket(Config::default());
         let client = Client::new(rocket).unwrap();
         let _ = create(&client);
         let mut response = delete(&client, 0);
@@ -219,9 +686,148 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_list() {
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+        let _ = create(&client);
+        let _ = create(&client);
+        let _ = delete(&client, 0);
+        let mut response = list(&client);
+
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.body_string().unwrap(),
+            json!({
+                    "ids": [1]
+                }).to_string()
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let solution_grid = include_str!("sudoku_solution.txt");
+
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+        let _ = create(&client);
+
+        let mut response = describe(&client, 0);
+        assert_eq!(response.status(), Status::Ok);
+        let info = body_json(&mut response);
+        assert_eq!(info["id"], 0);
+        assert_eq!(info["grid"], info["current_grid"]);
+        assert_eq!(info["solved"], false);
+        assert!(info["created_at"].as_u64().unwrap() > 0);
+        assert_ne!(info["grid"], solution_grid);
+        assert_eq!(
+            info["grid"].as_str().unwrap().replace(|c| c == ' ' || c == '\n', ""),
+            sudoku_grid.replace(|c| c == '|' || c == '\n', "")
+        );
+
+        let _ = solution(&client, 0);
+        let mut response = describe(&client, 0);
+        assert_eq!(response.status(), Status::Ok);
+        let info = body_json(&mut response);
+        assert_eq!(info["current_grid"], solution_grid);
+        assert_eq!(info["solved"], true);
+        assert!(info["last_used_at"].as_u64() >= info["created_at"].as_u64());
+    }
+
+    #[test]
+    fn test_clone() {
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+        let _ = create(&client);
+        let mut response = clone_solver(&client, 0);
+
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.body_string().unwrap(),
+            json!({
+                    "id": 1
+                }).to_string()
+        );
+
+        let solution = include_str!("sudoku_solution.txt");
+        let mut response = solution(&client, 1);
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.body_string().unwrap(),
+            json!({
+                    "solution": solution
+                }).to_string()
+        );
+    }
+
+    #[test]
+    fn test_solution_assuming() {
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+        let _ = create(&client);
+        let mut response = solution_assuming(&client, 0, 0, 0, 8);
+
+        let solution = include_str!("sudoku_solution.txt");
+
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.body_string().unwrap(),
+            json!({
+                    "solution": solution
+                }).to_string()
+        );
+
+        // Assumptions must not affect the solver itself.
+        let mut response = describe(&client, 0);
+        assert_eq!(body_json(&mut response)["solved"], false);
+    }
+
+    #[test]
+    fn test_err_solution_assuming_contradiction() {
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+        let _ = create(&client);
+        let mut response = solution_assuming(&client, 0, 0, 0, 1);
+
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(response.body_string().unwrap(), json!({
+                    "description": "solution for the specified grid couldn't be found"
+                }).to_string())
+    }
+
+    #[test]
+    fn test_err_bad_assignment() {
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+        let _ = create(&client);
+        let mut response = solution_assuming(&client, 0, 9, 0, 1);
+
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(response.body_string().unwrap(), json!({
+                    "description": "assignment is out of range"
+                }).to_string())
+    }
+
+    #[test]
+    fn test_usage() {
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+        let _ = create(&client);
+        let _ = create(&client);
+        let mut response = usage(&client);
+
+        assert_eq!(response.status(), Status::Ok);
+        let usage = body_json(&mut response);
+        assert_eq!(usage["solvers"], 2);
+        assert!(usage["bytes"].as_u64().unwrap() > 0);
+        assert_eq!(usage["max_bytes"], Value::Null);
+        assert_eq!(usage["max_solvers"], Value::Null);
+    }
+
     #[test]
     fn test_err_not_available() {
-        let rocket = super::create_rocket();
+        let rocket = super::create_rocket(Config::default());
         let client = Client::new(rocket).unwrap();
         let mut response = delete(&client, 0);
 
@@ -232,11 +838,182 @@ mod tests {
                 }).to_string())
     }
 
+    #[test]
+    fn test_priority_header() {
+        use rocket::http::Header;
+
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+
+        let response = client
+            .get("/")
+            .header(Header::new("X-Priority", "high"))
+            .dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let response = client
+            .get("/")
+            .header(Header::new("X-Priority", "urgent"))
+            .dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+
+    #[test]
+    fn test_solution_progress() {
+        let solution = include_str!("sudoku_solution.txt");
+
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+        let _ = create(&client);
+        let mut response = client.get("/0/solution/progress").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let body = response.body_string().unwrap();
+        let frames: Vec<Value> = body.lines()
+            .filter(|line| line.starts_with("data: "))
+            .map(|line| serde_json::from_str(&line["data: ".len()..]).unwrap())
+            .collect();
+        assert_eq!(
+            frames,
+            vec![
+                json!({ "done": 0, "total": 1 }),
+                json!({ "done": 1, "total": 1 }),
+                json!({ "solution": solution }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solution_progress_without_padding() {
+        use rocket::config::{Config as RocketConfig, Environment};
+
+        let solution = include_str!("sudoku_solution.txt");
+
+        let rocket_config = RocketConfig::build(Environment::Development)
+            .extra("sse_padding", false)
+            .finalize()
+            .unwrap();
+        let rocket = super::mount(::rocket::custom(rocket_config, false), Config::default());
+        let client = Client::new(rocket).unwrap();
+        let _ = create(&client);
+        let mut response = client.get("/0/solution/progress").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        let expected: String = vec![
+            json!({ "done": 0, "total": 1 }),
+            json!({ "done": 1, "total": 1 }),
+            json!({ "solution": solution }),
+        ].iter()
+            .map(|json| format!("data: {}\n\n", json))
+            .collect();
+        assert_eq!(response.body_string().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_events() {
+        use std::io::Read;
+        use super::SSE_FRAME_SIZE;
+
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+        let mut response = events(&client);
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.content_type(),
+            Some(ContentType::new("text", "event-stream"))
+        );
+
+        let _ = create(&client);
+
+        let mut frame = vec![0; SSE_FRAME_SIZE];
+        response
+            .body()
+            .unwrap()
+            .into_inner()
+            .read_exact(&mut frame)
+            .unwrap();
+        let frame = String::from_utf8(frame).unwrap();
+        let data = frame
+            .lines()
+            .find(|line| line.starts_with("data: "))
+            .unwrap();
+        let event: Value = serde_json::from_str(&data["data: ".len()..]).unwrap();
+        assert_eq!(event, json!({ "event": "created", "id": 0 }));
+        assert!(frame.ends_with("\n\n"));
+    }
+
+    #[test]
+    fn test_events_keep_alive() {
+        use std::io::Read;
+        use std::sync::mpsc;
+        use std::time::Duration;
+        use super::EventStream;
+
+        let (sender, receiver) = mpsc::channel();
+        let mut stream = EventStream::new(receiver, false);
+        stream.keep_alive = Duration::from_millis(10);
+
+        let mut buf = [0; 64];
+        let len = stream.read(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b": keep-alive\n\n");
+
+        drop(sender);
+        assert_eq!(stream.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_batch() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let solution = include_str!("sudoku_solution.txt");
+
+        let rocket = super::create_rocket(Config::default());
+        let client = Client::new(rocket).unwrap();
+        let batch = |stop_on_error| {
+            client
+                .post("/batch")
+                .header(ContentType::JSON)
+                .body(
+                    json!({
+                        "requests": [
+                            { "op": "create", "grid": sudoku_grid },
+                            { "op": "solve", "id": 42 },
+                            { "op": "solve", "id": 0 },
+                            { "op": "list" }
+                        ],
+                        "stop_on_error": stop_on_error
+                    }).to_string(),
+                )
+                .dispatch()
+        };
+
+        let mut response = batch(false);
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            body_json(&mut response),
+            json!({
+                "results": [
+                    { "id": 0 },
+                    {
+                        "description": "solver with specified id not available \
+                        at the moment or doesn't exist"
+                    },
+                    { "solution": solution },
+                    { "ids": [0] }
+                ]
+            })
+        );
+
+        let mut response = batch(true);
+        assert_eq!(response.status(), Status::Ok);
+        let results = body_json(&mut response);
+        assert_eq!(results["results"].as_array().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_err_solution_not_found() {
         let sudoku_grid = include_str!("bad_sudoku.txt");
 
-        let rocket = super::create_rocket();
+        let rocket = super::create_rocket(Config::default());
         let client = Client::new(rocket).unwrap();
         let _ = create_custom(&client, sudoku_grid);
         let mut response = solution(&client, 0);
@@ -249,7 +1026,7 @@ mod tests {
 
     #[test]
     fn test_err_bad_grid() {
-        let rocket = super::create_rocket();
+        let rocket = super::create_rocket(Config::default());
         let client = Client::new(rocket).unwrap();
         let mut response = create_custom(&client, "<bad grid>");
 
@@ -0,0 +1,228 @@
+//! Journal - recording of the executor traffic and its replay.
+//!
+//! Requests are recorded together with the responses to them by an
+//! interceptor, so it works with any transport. A journal can be replayed
+//! against a fresh executor to reproduce the recorded session.
+
+use std::fs::File;
+use std::io::{BufRead, BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::mpsc::channel;
+use std::time::{SystemTime, UNIX_EPOCH};
+use capnp::serialize;
+use capnp::message::{Builder, ReaderOptions};
+use capnproto::{build_req, build_resp};
+use context::{lock, unix_millis};
+use executor::{Config, Executor, Interceptor, Req, Resp, RespKind};
+use error::*;
+use api_capnp;
+
+/// Interceptor writing every request and the response to it into a journal.
+///
+/// Entries are written in the order of responses. Journals recorded with
+/// more than one worker might not replay exactly, since the order in which
+/// requests have been handled isn't preserved.
+pub struct Recorder<W> {
+    writer: Mutex<W>,
+}
+
+impl Recorder<BufWriter<File>> {
+    /// Record into a new file at `path`, truncating the existing one.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Recorder<BufWriter<File>>> {
+        let file = File::create(path)?;
+        Ok(Recorder::new(BufWriter::new(file)))
+    }
+}
+
+impl<W: Write + Send> Recorder<W> {
+    pub fn new(writer: W) -> Recorder<W> {
+        Recorder {
+            writer: Mutex::new(writer),
+        }
+    }
+
+    fn record(&self, req: &Req, resp: &Resp) -> Result<()> {
+        let mut message = Builder::new_default();
+        {
+            let mut entry = message.init_root::<api_capnp::journal_entry::Builder>();
+            entry.set_at(unix_millis(SystemTime::now()));
+            build_req(req, entry.borrow().init_req());
+            build_resp(resp.id, &resp.kind, entry.init_resp());
+        }
+
+        let mut writer = lock(&self.writer);
+        serialize::write_message(&mut *writer, &message)?;
+        // Flush every entry, so the journal is useful even after a crash.
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<W: Write + Send> Interceptor for Recorder<W> {
+    fn after(&self, req: &Req, resp: &mut Resp) {
+        // Journal is best effort, failing to write it mustn't fail requests.
+        let _ = self.record(req, resp);
+    }
+}
+
+/// Response that differs between the journal and its replay.
+///
+/// Timestamps of both responses are reset to the unix epoch.
+pub struct Mismatch {
+    /// Index of the entry in the journal.
+    pub index: usize,
+    pub recorded: Resp,
+    pub replayed: Resp,
+}
+
+/// Replay `journal` against a fresh executor configured with `config`
+/// and return responses that differ from the recorded ones.
+///
+/// Requests are sent one by one, waiting for the response to the previous
+/// one. Responses are compared in their wire encoding, ignoring timestamps.
+pub fn replay<R: BufRead>(mut journal: R, config: Config) -> Result<Vec<Mismatch>> {
+    let (tx, rx) = channel();
+    let executor = Executor::with_config(
+        Config {
+            workers: 1,
+            ..config
+        },
+        move |resp| {
+            let _ = tx.send(resp);
+        },
+    );
+
+    let mut mismatches = Vec::new();
+    let mut index = 0;
+    while !journal.fill_buf()?.is_empty() {
+        let message = serialize::read_message(&mut journal, ReaderOptions::new())?;
+        let entry = message.get_root::<api_capnp::journal_entry::Reader>()?;
+        let req = Req::from_reader(entry.get_req()?)?;
+        let mut recorded = Resp::from_reader(entry.get_resp()?)?;
+
+        executor.send(req);
+        let mut replayed = rx.recv().map_err(|_| {
+            Error::from(ErrorKind::Internal("executor has stopped".to_string()))
+        })?;
+
+        reset_timestamps(&mut recorded);
+        reset_timestamps(&mut replayed);
+        if wire_bytes(&recorded) != wire_bytes(&replayed) {
+            mismatches.push(Mismatch {
+                index,
+                recorded,
+                replayed,
+            });
+        }
+        index += 1;
+    }
+    Ok(mismatches)
+}
+
+fn reset_timestamps(resp: &mut Resp) {
+    fn reset(kind: &mut RespKind) {
+        match *kind {
+            RespKind::SolverInfo { ref mut info } => {
+                info.created = UNIX_EPOCH;
+                info.last_used = UNIX_EPOCH;
+            }
+            RespKind::Batch { ref mut results } => for result in results {
+                if let Ok(ref mut kind) = *result {
+                    reset(kind);
+                }
+            },
+            _ => {}
+        }
+    }
+
+    if let Ok(ref mut kind) = resp.kind {
+        reset(kind);
+    }
+}
+
+fn wire_bytes(resp: &Resp) -> Vec<u8> {
+    let mut message = Builder::new_default();
+    build_resp(
+        resp.id,
+        &resp.kind,
+        message.init_root::<api_capnp::resp::Builder>(),
+    );
+
+    let mut bytes = Vec::new();
+    serialize::write_message(&mut bytes, &message).expect("write_message should succeed");
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use std::sync::Arc;
+    use context::Limits;
+    use executor::{Priority, ReqKind};
+
+    /// Writer into a buffer that outlives the executor.
+    #[derive(Clone)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> ::std::io::Result<usize> {
+            lock(&self.0).write(buf)
+        }
+
+        fn flush(&mut self) -> ::std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn req(id: usize, kind: ReqKind) -> Req {
+        Req {
+            id,
+            kind,
+            priority: Priority::Normal,
+            report_progress: false,
+        }
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let journal = SharedBuf(Arc::new(Mutex::new(Vec::new())));
+        {
+            let (tx, rx) = channel();
+            let executor = Executor::with_config(
+                Config {
+                    interceptors: vec![Box::new(Recorder::new(journal.clone()))],
+                    ..Config::default()
+                },
+                move |resp| tx.send(resp).unwrap(),
+            );
+            executor.send(req(0, ReqKind::CreateSolver { grid: sudoku_grid.to_string() }));
+            executor.send(req(1, ReqKind::CreateSolver { grid: sudoku_grid.to_string() }));
+            executor.send(req(2, ReqKind::Solve { id: 1 }));
+            executor.send(req(3, ReqKind::DescribeSolver { id: 1 }));
+            executor.send(req(4, ReqKind::Destroy { id: 42 }));
+            let _: Vec<Resp> = rx.iter().take(5).collect();
+        }
+        let journal = lock(&journal.0).clone();
+
+        let mismatches = replay(Cursor::new(&journal), Config::default()).unwrap();
+        assert!(mismatches.is_empty());
+
+        // Second solver can't be created in a smaller session, so
+        // everything involving it turns out differently.
+        let config = Config {
+            limits: Limits {
+                max_solvers: Some(1),
+                ..Limits::default()
+            },
+            ..Config::default()
+        };
+        let mismatches = replay(Cursor::new(&journal), config).unwrap();
+        let indices: Vec<usize> = mismatches.iter().map(|mismatch| mismatch.index).collect();
+        assert_eq!(indices, vec![1, 2, 3]);
+        assert!(mismatches[0].recorded.kind.is_ok());
+        assert!(mismatches[0].replayed.kind.is_err());
+    }
+}
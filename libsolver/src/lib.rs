@@ -1,4 +1,5 @@
 #![feature(plugin)]
+#![cfg_attr(test, feature(test))]
 #![plugin(rocket_codegen)]
 
 extern crate rocket;
@@ -9,19 +10,50 @@ extern crate serde_derive;
 #[macro_use]
 extern crate error_chain;
 extern crate capnp;
+#[macro_use]
+extern crate lazy_static;
+extern crate futures;
+#[macro_use]
+extern crate capnp_rpc;
+extern crate tokio_core;
+extern crate tokio_io;
+#[cfg(unix)]
+extern crate tokio_uds;
+#[cfg(test)]
+extern crate serde_json;
+#[cfg(test)]
+extern crate test;
 
-// These mods must be public in order to be exported.
+// Transports, which export the C API. Public for Rust hosts as well,
+// e.g. `capnproto::init_with_config`.
 pub mod ffi;
 pub mod http;
 pub mod capnproto;
+#[cfg(unix)]
+pub mod rpc;
 
+mod client;
 mod error;
 mod solver;
 mod context;
 mod executor;
+mod journal;
+mod handles;
+
+pub use client::{Client, Notification, ResponseFuture};
+pub use context::{Event, EventKind, Limits, SolverInfo, Usage};
+pub use error::{Error, ErrorKind, Result};
+pub use executor::{Config, Interceptor, Overflow, Priority, Req, ReqKind, Resp, RespKind};
+pub use journal::{replay, Mismatch, Recorder};
+pub use solver::{Assignment, Progress};
 
 // Include autogenerated bindings.
 mod api_capnp {
     #![allow(unused)]
     include!("api_capnp.rs");
 }
+
+mod rpc_capnp {
+    #![allow(unused)]
+    include!("rpc_capnp.rs");
+}
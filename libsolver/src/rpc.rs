@@ -0,0 +1,372 @@
+//! Capability-based interface to the solvers, see `common/rpc.capnp`.
+//!
+//! Unlike `capnproto`, requests aren't processed by an `Executor`, but by
+//! the event loop serving the connection, so neither interceptors nor the
+//! journal apply here. Solvers are destroyed as soon as the last reference
+//! to their capability is dropped, e.g. when the client disconnects.
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::os::unix::io::IntoRawFd;
+use std::os::unix::net;
+use std::sync::Arc;
+use std::thread;
+use capnp::Error as CapnpError;
+use capnp::capability::Promise;
+use capnp_rpc::{self, twoparty, RpcSystem};
+use capnp_rpc::rpc_twoparty_capnp::Side;
+use futures::{Future, Stream};
+use tokio_core::reactor::{Core, Handle};
+use tokio_io::AsyncRead;
+use tokio_uds::{UnixListener, UnixStream};
+use context::{self, unix_millis, Context, Limits};
+use solver::Assignment;
+use error::*;
+use ffi::{catch, non_null, status};
+use rpc_capnp::{solver, solver_factory};
+
+/// Errors are passed as exceptions, which carry only the description.
+fn failed(e: context::Error) -> CapnpError {
+    CapnpError::failed(e.to_string())
+}
+
+struct SolverFactoryImpl {
+    ctx: Arc<Context>,
+}
+
+impl solver_factory::Server for SolverFactoryImpl {
+    fn create(
+        &mut self,
+        params: solver_factory::CreateParams,
+        mut results: solver_factory::CreateResults,
+    ) -> Promise<(), CapnpError> {
+        let grid = pry!(pry!(params.get()).get_grid());
+        let id = pry!(self.ctx.new_solver(grid).map_err(failed));
+        results.get().set_solver(SolverImpl::client(self.ctx.clone(), id));
+        Promise::ok(())
+    }
+
+    fn usage(
+        &mut self,
+        _: solver_factory::UsageParams,
+        mut results: solver_factory::UsageResults,
+    ) -> Promise<(), CapnpError> {
+        let usage = self.ctx.usage();
+        let limits = self.ctx.limits();
+        let mut resp = results.get().init_usage();
+        resp.set_bytes(usage.bytes as u64);
+        resp.set_solvers(usage.solvers as u32);
+        resp.set_max_bytes(limits.max_bytes.unwrap_or(0) as u64);
+        resp.set_max_solvers(limits.max_solvers.unwrap_or(0) as u32);
+        Promise::ok(())
+    }
+}
+
+/// Solver `id` of `ctx`, owned by the capability.
+struct SolverImpl {
+    ctx: Arc<Context>,
+    id: usize,
+}
+
+impl SolverImpl {
+    fn client(ctx: Arc<Context>, id: usize) -> solver::Client {
+        solver::ToClient::new(SolverImpl { ctx, id }).from_server::<capnp_rpc::Server>()
+    }
+}
+
+impl solver::Server for SolverImpl {
+    fn solve(
+        &mut self,
+        _: solver::SolveParams,
+        mut results: solver::SolveResults,
+    ) -> Promise<(), CapnpError> {
+        let solution = pry!(self.ctx.solve(self.id).map_err(failed));
+        results.get().set_solution(&solution);
+        Promise::ok(())
+    }
+
+    fn solve_assuming(
+        &mut self,
+        params: solver::SolveAssumingParams,
+        mut results: solver::SolveAssumingResults,
+    ) -> Promise<(), CapnpError> {
+        let assumptions_reader = pry!(pry!(params.get()).get_assumptions());
+        let assumptions: Vec<Assignment> = (0..assumptions_reader.len())
+            .map(|i| {
+                let assumption = assumptions_reader.get(i);
+                Assignment {
+                    row: assumption.get_row(),
+                    col: assumption.get_col(),
+                    digit: assumption.get_digit(),
+                }
+            })
+            .collect();
+        let solution = pry!(
+            self.ctx
+                .solve_assuming(self.id, &assumptions)
+                .map_err(failed)
+        );
+        results.get().set_solution(&solution);
+        Promise::ok(())
+    }
+
+    fn hints(
+        &mut self,
+        params: solver::HintsParams,
+        mut results: solver::HintsResults,
+    ) -> Promise<(), CapnpError> {
+        let count = pry!(params.get()).get_count() as usize;
+        let hints = pry!(self.ctx.hints(self.id, count).map_err(failed));
+        let mut hints_builder = results.get().init_hints(hints.len() as u32);
+        for (i, hint) in hints.iter().enumerate() {
+            let mut hint_builder = hints_builder.borrow().get(i as u32);
+            hint_builder.set_row(hint.row);
+            hint_builder.set_col(hint.col);
+            hint_builder.set_digit(hint.digit);
+        }
+        Promise::ok(())
+    }
+
+    fn describe(
+        &mut self,
+        _: solver::DescribeParams,
+        mut results: solver::DescribeResults,
+    ) -> Promise<(), CapnpError> {
+        let info = pry!(self.ctx.describe(self.id).map_err(failed));
+        let mut resp = results.get().init_info();
+        resp.set_id(info.id as u32);
+        resp.set_grid(&info.grid);
+        resp.set_current_grid(&info.current_grid);
+        resp.set_created_at(unix_millis(info.created));
+        resp.set_last_used_at(unix_millis(info.last_used));
+        resp.set_solved(info.solved);
+        Promise::ok(())
+    }
+
+    fn clone(
+        &mut self,
+        _: solver::CloneParams,
+        mut results: solver::CloneResults,
+    ) -> Promise<(), CapnpError> {
+        let id = pry!(self.ctx.clone_solver(self.id).map_err(failed));
+        results.get().set_solver(SolverImpl::client(self.ctx.clone(), id));
+        Promise::ok(())
+    }
+}
+
+impl Drop for SolverImpl {
+    fn drop(&mut self) {
+        // Nothing to report the failure to. The solver can't be busy,
+        // since calls on a capability are processed one at a time.
+        let _ = self.ctx.destroy(self.id);
+    }
+}
+
+/// Bootstrap capability serving the solvers of `ctx`.
+pub fn factory(ctx: Arc<Context>) -> solver_factory::Client {
+    solver_factory::ToClient::new(SolverFactoryImpl { ctx }).from_server::<capnp_rpc::Server>()
+}
+
+/// Serve `factory` over `stream` on the event loop of `handle`, until
+/// the peer disconnects.
+pub fn serve_stream(stream: UnixStream, factory: solver_factory::Client, handle: &Handle) {
+    let (reader, writer) = stream.split();
+    let network = twoparty::VatNetwork::new(reader, writer, Side::Server, Default::default());
+    let rpc_system = RpcSystem::new(Box::new(network), Some(factory.client));
+    handle.spawn(rpc_system.map_err(|_| ()));
+}
+
+/// Serve the solvers of `ctx` to every connection accepted by `listener`.
+/// All the connections share `ctx`.
+///
+/// Be aware! This will block the calling thread. Returns only if accepting
+/// connections has failed.
+pub fn serve(listener: net::UnixListener, ctx: Arc<Context>) -> Result<()> {
+    let mut core = Core::new()?;
+    let handle = core.handle();
+    let listener = UnixListener::from_listener(listener, &handle)?;
+    let factory = factory(ctx);
+
+    let done = listener.incoming().for_each(|(stream, _)| {
+        serve_stream(stream, factory.clone(), &handle);
+        Ok(())
+    });
+    core.run(done)?;
+    Ok(())
+}
+
+/// Serve the solvers of `ctx` on a new thread to the returned end of
+/// a socket pair. The thread exits once the returned end is closed.
+pub fn serve_pair(ctx: Arc<Context>) -> Result<net::UnixStream> {
+    let (client, server) = net::UnixStream::pair()?;
+    thread::Builder::new()
+        .name("rpc".to_string())
+        .spawn(move || -> Result<()> {
+            let mut core = Core::new()?;
+            let stream = UnixStream::from_stream(server, &core.handle())?;
+            let (reader, writer) = stream.split();
+            let network =
+                twoparty::VatNetwork::new(reader, writer, Side::Server, Default::default());
+            let rpc_system = RpcSystem::new(Box::new(network), Some(factory(ctx).client));
+            core.run(rpc_system)?;
+            Ok(())
+        })?;
+    Ok(client)
+}
+
+fn context_with_limits(max_bytes: usize, max_solvers: usize) -> Arc<Context> {
+    fn non_zero(limit: usize) -> Option<usize> {
+        if limit == 0 {
+            None
+        } else {
+            Some(limit)
+        }
+    }
+
+    Arc::new(Context::with_limits(Limits {
+        max_bytes: non_zero(max_bytes),
+        max_solvers: non_zero(max_solvers),
+    }))
+}
+
+/// Serve `rpc.capnp` on a unix socket bound at `path`. Zero means unlimited
+/// for `max_bytes` and `max_solvers`, which are shared by all connections.
+///
+/// Be aware! This will block the calling thread. Returns only if the
+/// socket couldn't be bound or accepting connections has failed.
+#[no_mangle]
+pub extern "C" fn rpc_serve_unix(path: *const c_char, max_bytes: usize, max_solvers: usize) -> u32 {
+    status(catch(|| {
+        non_null(path, "path")?;
+        let path = unsafe { CStr::from_ptr(path) }
+            .to_str()
+            .map_err(|_| ErrorKind::InvalidArgument("`path` isn't utf-8".to_string()))?;
+        let listener = net::UnixListener::bind(path)?;
+        serve(listener, context_with_limits(max_bytes, max_solvers))
+    }))
+}
+
+/// Serve `rpc.capnp` on a background thread to a connected socket, which
+/// is stored to `fd`. The caller owns the socket, closing it stops the thread.
+#[no_mangle]
+pub extern "C" fn rpc_serve_pair(max_bytes: usize, max_solvers: usize, fd: *mut c_int) -> u32 {
+    status(catch(|| {
+        non_null(fd, "fd")?;
+        let stream = serve_pair(context_with_limits(max_bytes, max_solvers))?;
+        unsafe {
+            *fd = stream.into_raw_fd();
+        }
+        Ok(())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::env;
+    use std::process;
+
+    const SUDOKU: &'static str = include_str!("sudoku.txt");
+    const SOLUTION: &'static str = include_str!("sudoku_solution.txt");
+
+    /// Connect to the bootstrap capability over `stream`.
+    fn connect(stream: UnixStream, handle: &Handle) -> solver_factory::Client {
+        let (reader, writer) = stream.split();
+        let network = twoparty::VatNetwork::new(reader, writer, Side::Client, Default::default());
+        let mut rpc_system = RpcSystem::new(Box::new(network), None);
+        let factory = rpc_system.bootstrap(Side::Server);
+        handle.spawn(rpc_system.map_err(|_| ()));
+        factory
+    }
+
+    /// Create a solver and solve it in a single round trip.
+    fn create_and_solve(factory: &solver_factory::Client) -> Promise<String, CapnpError> {
+        let mut create = factory.create_request();
+        create.get().set_grid(SUDOKU);
+        let solver = create.send().pipeline.get_solver();
+        Promise::from_future(solver.solve_request().send().promise.and_then(|resp| {
+            Ok(resp.get()?.get_solution()?.to_string())
+        }))
+    }
+
+    #[test]
+    fn pipelined_create_and_solve() {
+        let mut core = Core::new().unwrap();
+        let handle = core.handle();
+        let ctx = Arc::new(Context::new());
+        let (client, server) = UnixStream::pair(&handle).unwrap();
+        serve_stream(server, factory(ctx.clone()), &handle);
+        let factory = connect(client, &handle);
+
+        // Capabilities of solvers are held by the client.
+        let mut create = factory.create_request();
+        create.get().set_grid(SUDOKU);
+        let solver = create.send().pipeline.get_solver();
+        let solved = core.run(solver.describe_request().send().promise.and_then(|resp| {
+            Ok(resp.get()?.get_info()?.get_solved())
+        })).unwrap();
+        assert!(!solved);
+        let solvers = core.run(factory.usage_request().send().promise.and_then(|resp| {
+            Ok(resp.get()?.get_usage()?.get_solvers())
+        })).unwrap();
+        assert_eq!(solvers, 1);
+        assert_eq!(ctx.list().len(), 1);
+
+        let solution = core.run(create_and_solve(&factory)).unwrap();
+        assert_eq!(solution, SOLUTION);
+    }
+
+    #[test]
+    fn errors_are_exceptions() {
+        let mut core = Core::new().unwrap();
+        let handle = core.handle();
+        let (client, server) = UnixStream::pair(&handle).unwrap();
+        serve_stream(server, factory(Arc::new(Context::new())), &handle);
+        let factory = connect(client, &handle);
+
+        let mut create = factory.create_request();
+        create.get().set_grid("not a sudoku");
+        let solver = create.send().pipeline.get_solver();
+        let result = core.run(solver.describe_request().send().promise);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn serve_pair_in_background() {
+        let mut core = Core::new().unwrap();
+        let handle = core.handle();
+        let stream = serve_pair(Arc::new(Context::new())).unwrap();
+        let factory = connect(UnixStream::from_stream(stream, &handle).unwrap(), &handle);
+
+        let solution = core.run(create_and_solve(&factory)).unwrap();
+        assert_eq!(solution, SOLUTION);
+    }
+
+    #[test]
+    fn serve_unix_socket() {
+        let dir = env::temp_dir().join(format!("turbosolver-rpc-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("socket");
+        let _ = fs::remove_file(&path);
+        let listener = net::UnixListener::bind(&path).unwrap();
+        thread::spawn(move || serve(listener, Arc::new(Context::new())));
+
+        let mut core = Core::new().unwrap();
+        let handle = core.handle();
+        let stream = net::UnixStream::connect(&path).unwrap();
+        let factory = connect(UnixStream::from_stream(stream, &handle).unwrap(), &handle);
+
+        let solution = core.run(create_and_solve(&factory)).unwrap();
+        assert_eq!(solution, SOLUTION);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn dropping_capability_destroys_solver() {
+        let ctx = Arc::new(Context::new());
+        let id = ctx.new_solver(SUDOKU).unwrap();
+        drop(SolverImpl { ctx: ctx.clone(), id });
+        assert!(ctx.list().is_empty());
+    }
+}
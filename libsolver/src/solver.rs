@@ -1,4 +1,7 @@
 //! Solver itself. You can't change the grid after Solver was created.
+//!
+//! The immutable part of a solver is kept in `Puzzle`, which can be
+//! shared between solvers of the same grid.
 
 // I don't want to implement sudoku solver here by myself, so I just
 // pick some crate that already does the job.
@@ -8,17 +11,194 @@ extern crate sudoku;
 use error::*;
 use self::sudoku::Sudoku;
 use std::fmt;
+use std::mem;
+use std::sync::{Arc, Mutex};
 
-pub struct Solver(Sudoku);
+/// Extra assignment of a `digit` to a cell, used to explore
+/// "what-if" branches. `row` and `col` are zero-based.
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct Assignment {
+    pub row: u8,
+    pub col: u8,
+    pub digit: u8,
+}
+
+impl Assignment {
+    pub fn is_valid(&self) -> bool {
+        self.row < 9 && self.col < 9 && self.digit >= 1 && self.digit <= 9
+    }
+}
+
+/// Approximate size of an OS lock, e.g. `pthread_mutex_t`.
+const LOCK_SIZE: usize = 64;
+
+/// Parsed grid together with its solution, which is computed on first use.
+pub struct Puzzle {
+    grid: Sudoku,
+    solution: Mutex<Option<Option<Sudoku>>>,
+}
+
+impl Puzzle {
+    pub fn from_str(grid: &str) -> Result<Puzzle> {
+        let sudoku = Sudoku::from_str(grid).map_err(ParseError)?;
+        Ok(Puzzle {
+            grid: sudoku,
+            solution: Mutex::new(None),
+        })
+    }
+
+    /// Canonical representation of the grid: one byte per cell, `0` for
+    /// empty ones. Grids that differ only in formatting have equal keys.
+    pub fn key(&self) -> Vec<u8> {
+        self.grid.iter().map(|cell| cell.unwrap_or(0)).collect()
+    }
+
+    /// Approximate amount of heap memory occupied by the puzzle shared
+    /// via `Arc`, including the cache of its solution.
+    pub fn heap_size(&self) -> usize {
+        // Reference counts of the `Arc`, and the lock guarding the cache,
+        // which std keeps in a box of its own.
+        2 * mem::size_of::<usize>() + mem::size_of::<Puzzle>() + LOCK_SIZE
+    }
+
+    /// Returns the solution, solving the grid only the first time.
+    fn solution(&self) -> Option<Sudoku> {
+        // The cache is always consistent, so a panic elsewhere must not
+        // make the puzzle unusable.
+        let mut solution = ::context::lock(&self.solution);
+        if solution.is_none() {
+            *solution = Some(self.grid.solve_one());
+        }
+        solution.unwrap()
+    }
+}
+
+/// Progress of a long-running operation: `done` out of `total` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    pub done: u32,
+    pub total: u32,
+}
+
+#[derive(Clone)]
+pub struct Solver {
+    puzzle: Arc<Puzzle>,
+    current: Sudoku,
+}
 
 impl Solver {
     pub fn from_str(grid: &str) -> Result<Solver> {
-        let sudoku = Sudoku::from_str(grid).map_err(ParseError)?;
-        Ok(Solver(sudoku))
+        let puzzle = Puzzle::from_str(grid)?;
+        Ok(Solver::new(Arc::new(puzzle)))
+    }
+
+    pub fn new(puzzle: Arc<Puzzle>) -> Solver {
+        let current = puzzle.grid;
+        Solver { puzzle, current }
+    }
+
+    pub fn puzzle(&self) -> &Arc<Puzzle> {
+        &self.puzzle
+    }
+
+    pub fn into_puzzle(self) -> Arc<Puzzle> {
+        self.puzzle
     }
 
     pub fn solve(&mut self) -> Option<String> {
-        self.0.solve_one().map(|x| x.to_string())
+        match self.puzzle.solution() {
+            Some(solution) => {
+                self.current = solution;
+                Some(solution.to_string())
+            }
+            None => None,
+        }
+    }
+
+    /// Same as `solve`, but reports progress to `progress`.
+    ///
+    /// The underlying solver doesn't report its own progress, so solving
+    /// is a single step.
+    pub fn solve_with_progress(&mut self, progress: &mut FnMut(Progress)) -> Option<String> {
+        progress(Progress { done: 0, total: 1 });
+        let solution = self.solve();
+        progress(Progress { done: 1, total: 1 });
+        solution
+    }
+
+    /// Solve the grid as if `assumptions` were part of it. The solver
+    /// itself is left untouched.
+    ///
+    /// Returns `None` if there is no solution, including the case when
+    /// an assumption contradicts the grid. All `assumptions` must be valid.
+    pub fn solve_assuming(&self, assumptions: &[Assignment]) -> Option<String> {
+        let mut cells: Vec<Option<u8>> = self.puzzle.grid.iter().collect();
+        for assumption in assumptions {
+            let cell = &mut cells[assumption.row as usize * 9 + assumption.col as usize];
+            match *cell {
+                Some(digit) if digit != assumption.digit => return None,
+                _ => *cell = Some(assumption.digit),
+            }
+        }
+
+        let grid: String = cells
+            .chunks(9)
+            .map(|row| {
+                let mut line: String = row.iter()
+                    .map(|cell| match *cell {
+                        Some(digit) => (b'0' + digit) as char,
+                        None => '_',
+                    })
+                    .collect();
+                line.push('\n');
+                line
+            })
+            .collect();
+
+        Sudoku::from_str(&grid)
+            .ok()
+            .and_then(|sudoku| sudoku.solve_one())
+            .map(|solution| solution.to_string())
+    }
+
+    /// Up to `count` cells of the solution which aren't filled in the
+    /// current grid yet, in row-major order. The solver itself is left
+    /// untouched. Returns `None` if there is no solution.
+    pub fn hints(&self, count: usize) -> Option<Vec<Assignment>> {
+        let solution = match self.puzzle.solution() {
+            Some(solution) => solution,
+            None => return None,
+        };
+        let hints = self.current
+            .iter()
+            .zip(solution.iter())
+            .enumerate()
+            .filter_map(|(index, cells)| match cells {
+                (None, Some(digit)) => Some(Assignment {
+                    row: (index / 9) as u8,
+                    col: (index % 9) as u8,
+                    digit,
+                }),
+                _ => None,
+            })
+            .take(count)
+            .collect();
+        Some(hints)
+    }
+
+    /// Grid this solver was created with.
+    pub fn grid(&self) -> String {
+        self.puzzle.grid.to_string()
+    }
+
+    /// Current state of the grid. It is filled in with the solution
+    /// once the solver has been solved.
+    pub fn current_grid(&self) -> String {
+        self.current.to_string()
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.current.is_solved()
     }
 }
 
@@ -58,4 +238,76 @@ mod tests {
         let solution = solver.solve().unwrap();
         assert_eq!(solution, expected_solution);
     }
+
+    #[test]
+    fn current_grid_is_filled_in_after_solve() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let expected_solution = include_str!("sudoku_solution.txt");
+
+        let mut solver = Solver::from_str(sudoku_grid).unwrap();
+        assert!(!solver.is_solved());
+        assert_eq!(solver.current_grid(), solver.grid());
+
+        solver.solve().unwrap();
+        assert!(solver.is_solved());
+        assert_eq!(solver.current_grid(), expected_solution);
+        assert_ne!(solver.grid(), expected_solution);
+    }
+
+    #[test]
+    fn shared_puzzle() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let expected_solution = include_str!("sudoku_solution.txt");
+
+        let puzzle = Arc::new(Puzzle::from_str(sudoku_grid).unwrap());
+        let mut first = Solver::new(Arc::clone(&puzzle));
+        let second = Solver::new(Arc::clone(&puzzle));
+
+        assert_eq!(first.solve().unwrap(), expected_solution);
+        assert!(first.is_solved());
+        assert!(!second.is_solved());
+        assert_eq!(second.current_grid(), second.grid());
+    }
+
+    #[test]
+    fn solve_assuming() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let expected_solution = include_str!("sudoku_solution.txt");
+
+        let solver = Solver::from_str(sudoku_grid).unwrap();
+        let consistent = [Assignment { row: 0, col: 0, digit: 8 }];
+        assert_eq!(solver.solve_assuming(&consistent).unwrap(), expected_solution);
+
+        // 1 is already present in the top-left box.
+        let contradicting = [Assignment { row: 0, col: 0, digit: 1 }];
+        assert_eq!(solver.solve_assuming(&contradicting), None);
+
+        // Overrides a given cell.
+        let overriding = [Assignment { row: 0, col: 3, digit: 1 }];
+        assert_eq!(solver.solve_assuming(&overriding), None);
+
+        assert!(!solver.is_solved());
+    }
+
+    #[test]
+    fn hints() {
+        let sudoku_grid = include_str!("sudoku.txt");
+        let expected_solution: Vec<u8> = include_str!("sudoku_solution.txt")
+            .bytes()
+            .filter(|&byte| byte >= b'1' && byte <= b'9')
+            .map(|byte| byte - b'0')
+            .collect();
+
+        let mut solver = Solver::from_str(sudoku_grid).unwrap();
+        let hints = solver.hints(3).unwrap();
+        assert_eq!(hints.len(), 3);
+        for hint in &hints {
+            assert!(hint.is_valid());
+            assert_eq!(hint.digit, expected_solution[hint.row as usize * 9 + hint.col as usize]);
+        }
+        assert!(!solver.is_solved());
+
+        solver.solve().unwrap();
+        assert!(solver.hints(3).unwrap().is_empty());
+    }
 }